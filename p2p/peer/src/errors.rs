@@ -0,0 +1,42 @@
+use std::{error, fmt};
+
+use openssl::error::ErrorStack;
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidKey,
+    UnsupportedKeyType(u64),
+    RSAKeyTooSmall(u32),
+    InvalidPeerID,
+    NotPeerIDCodec(u64),
+    OpenSSL(ErrorStack),
+    CID(cid::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidKey => write!(f, "invalid key encoding"),
+            Self::UnsupportedKeyType(v) => write!(f, "unsupported key type {}", v),
+            Self::RSAKeyTooSmall(v) => write!(f, "rsa key of {} bits is too small", v),
+            Self::InvalidPeerID => write!(f, "invalid peer id"),
+            Self::NotPeerIDCodec(v) => write!(f, "cid codec 0x{:x} is not libp2p-key", v),
+            Self::OpenSSL(e) => write!(f, "{}", e),
+            Self::CID(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<ErrorStack> for Error {
+    fn from(e: ErrorStack) -> Self {
+        Self::OpenSSL(e)
+    }
+}
+
+impl From<cid::Error> for Error {
+    fn from(e: cid::Error) -> Self {
+        Self::CID(e)
+    }
+}
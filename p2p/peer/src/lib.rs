@@ -1,6 +1,22 @@
-#[allow(dead_code)]
+mod errors;
 mod peer_id;
 #[allow(dead_code)]
 mod peer_record;
-#[allow(dead_code)]
 mod pkey;
+mod proto;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+pub use errors::*;
+
+pub use peer_id::*;
+pub use pkey::*;
+
+// decodes the hex test vectors
+#[cfg(test)]
+pub(crate) fn unhex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
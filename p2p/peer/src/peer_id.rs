@@ -1,22 +1,261 @@
-use multibase::Encoding;
-use multihash::multihash;
+use std::{fmt, str::FromStr};
 
-use crate::pkey;
+use cid::CID;
+use multibase::{Decoder, Encoder};
+use multihash::{multihash, split_multihash};
 
+use crate::{Error, PublicKey};
+
+// keys encoding to at most this many bytes are inlined in the peer id
+const MAX_INLINE_KEY_LEN: usize = 42;
+
+const IDENTITY: u64 = 0x00;
+const LIBP2P_KEY: u64 = 0x72;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct PeerID {
-    hash: Vec<u8>,
+    pub(crate) hash: Vec<u8>,
+}
+
+impl PeerID {
+    /// Takes the multihash of a peer id.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        split_multihash(bytes).ok_or(Error::InvalidPeerID)?;
+
+        Ok(Self {
+            hash: bytes.to_vec(),
+        })
+    }
+
+    /// The multihash of the peer id.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.hash.clone()
+    }
+
+    /// The CIDv1 with the libp2p-key codec, shown in base36 in ipns names.
+    pub fn to_cid(&self) -> CID {
+        CID::new("cidv1", "libp2p-key", self.hash.clone()).expect("cidv1 takes any multihash")
+    }
+
+    /// Recovers the public key inlined in the peer id, `None` when the key
+    /// was hashed.
+    pub fn extract_public_key(&self) -> Result<Option<PublicKey>, Error> {
+        match split_multihash(&self.hash) {
+            Some((IDENTITY, encoded)) => PublicKey::from_protobuf(encoded).map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl TryFrom<&PublicKey> for PeerID {
+    type Error = Error;
+
+    /// Derives the peer id as the libp2p spec does, the multihash of the
+    /// protobuf encoded key, with the identity hash for small keys such as
+    /// ed25519 and secp256k1 and sha2-256 otherwise.
+    fn try_from(public_key: &PublicKey) -> Result<Self, Self::Error> {
+        let encoded = public_key.to_protobuf()?;
+
+        let hash = if encoded.len() <= MAX_INLINE_KEY_LEN {
+            let mut hash = vec![IDENTITY as u8, encoded.len() as u8];
+            hash.extend_from_slice(&encoded);
+            hash
+        } else {
+            multihash("sha2-256", &encoded)
+        };
+
+        Ok(PeerID { hash })
+    }
 }
 
-impl From<&pkey::PubKey> for PeerID {
-    fn from(prikey: &pkey::PubKey) -> Self {
-        let hash = multihash("sha2-256", &prikey.raw_key());
+impl TryFrom<&CID> for PeerID {
+    type Error = Error;
+
+    fn try_from(cid: &CID) -> Result<Self, Self::Error> {
+        if cid.codec() != LIBP2P_KEY {
+            return Err(Error::NotPeerIDCodec(cid.codec()));
+        }
+
+        Self::from_bytes(cid.hash())
+    }
+}
 
-        PeerID { hash }
+// peer ids are base58btc without the multibase prefix
+impl fmt::Display for PeerID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&multibase::Base58Bitcoin::encode(&self.hash)[1..])
     }
 }
 
 impl From<&PeerID> for String {
     fn from(peer_id: &PeerID) -> Self {
-        multibase::Base58Bitcoin::encode(&peer_id.hash)
+        peer_id.to_string()
+    }
+}
+
+impl FromStr for PeerID {
+    type Err = Error;
+
+    /// Parses the legacy base58btc form, starting with `Qm` or `1`, or any
+    /// multibase CIDv1 with the libp2p-key codec.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("Qm") || s.starts_with('1') {
+            let hash = multibase::Base58Bitcoin::decode(&format!("z{}", s));
+            if hash.is_empty() {
+                return Err(Error::InvalidPeerID);
+            }
+
+            return Self::from_bytes(&hash);
+        }
+
+        Self::try_from(&CID::from_str(s)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{unhex, KeyType, PrivateKey};
+
+    fn peer_id(key_type: KeyType, raw: &str) -> String {
+        let public_key = PublicKey::from_raw(key_type, &unhex(raw)).unwrap();
+
+        String::from(&public_key.to_peer_id().unwrap())
+    }
+
+    const ED25519_ID: &str = "12D3KooWBtg3aaRMjxwedh83aGiUkwSxDwUZkzuJcfaqUmo7R3pq";
+    const RSA_ID: &str = "QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC";
+
+    // keys from the test vectors of the libp2p peer id spec
+    #[test]
+    fn test_spec_vectors() {
+        assert_eq!(
+            peer_id(
+                KeyType::Ed25519,
+                "1ed1e8fae2c4a144b8be8fd4b47bf3d3b34b871c3cacf6010f0e42d474fce27e"
+            ),
+            "12D3KooWBtg3aaRMjxwedh83aGiUkwSxDwUZkzuJcfaqUmo7R3pq"
+        );
+        assert_eq!(
+            peer_id(
+                KeyType::Secp256k1,
+                "037777e994e452c21604f91de093ce415f5432f701dd8cd1a7a6fea0e630bfca99"
+            ),
+            "16Uiu2HAmLhLvBoYaoZfaMUKuibM6ac163GwKY74c5kiSLg5KvLpY"
+        );
+    }
+
+    #[test]
+    fn test_rsa() {
+        let der = "30820122300d06092a864886f70d01010105000382010f003082010a0282010100a54e42c3a90c79a78dfe246fe82f1e6df552a85ea66de414a35fb63e4f73ae0a077465e69bcb6692f6295eadafe780bc39800f0c79a2ee6d3e098a96e416ec9d8337b6a7275173dc64dc9490a3c071e0ae438e5e9d757781e32184417400c6711790d1214e2d5e250a51e4f8d04de1a1125ee51ae9eec07e3cec772508d7e9589f85246921b3941b691aa8c1e3e0f18f8158ddd0153dcaf9b5c685d317cf81c4ce3426c9d64a39be0d98140ae47339dcd08d0131a4a1a61370a3a3fb240c311a78c6b14c6b78652104622ad16bd6492fc9f199b54188a7907caacc1ecf54bbacda5285880edf94de2ca93327c26d1143b25dbb15abc12d133f2fea510f57723f0203010001";
+
+        assert_eq!(
+            peer_id(KeyType::RSA, der),
+            "QmRucGiGEV4uBvykMbbJsxiGmdL7R4L3A5gCjp1uQBLBDL"
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        for (key_type, prefix) in [
+            (KeyType::Ed25519, "12D3KooW"),
+            (KeyType::Secp256k1, "16Uiu2HA"),
+            (KeyType::RSA, "Qm"),
+        ] {
+            let public_key = PrivateKey::generate(key_type)
+                .unwrap()
+                .public_key()
+                .unwrap();
+            let id = String::from(&public_key.to_peer_id().unwrap());
+
+            assert!(id.starts_with(prefix), "{:?} {}", key_type, id);
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        for s in [ED25519_ID, RSA_ID] {
+            let peer_id = PeerID::from_str(s).unwrap();
+            assert_eq!(peer_id.to_string(), s);
+            assert_eq!(PeerID::from_bytes(&peer_id.to_bytes()).unwrap(), peer_id);
+
+            // the cid form in every base parses to the same id
+            let cid = peer_id.to_cid();
+            for encoded in [
+                cid.encode::<multibase::Base36>(),
+                cid.encode::<multibase::Base32>(),
+                cid.encode::<multibase::Base58Bitcoin>(),
+            ] {
+                assert_eq!(PeerID::from_str(&encoded).unwrap(), peer_id, "{}", encoded);
+            }
+        }
+
+        let peer_id = PeerID::from_str(ED25519_ID).unwrap();
+        assert_eq!(
+            peer_id.to_cid().encode::<multibase::Base36>(),
+            "k51qzi5uqu5dgy8qsq67hbz73jqkw87l3fgf4a91qb0d9b5173tir7n4vxk1oe"
+        );
+        assert_eq!(
+            peer_id.to_cid().to_string(),
+            "bafzaajaiaejcahwr5d5ofrfbis4l5d6uwr57hu5tjodrypfm6yaq6dsc2r2pzyt6"
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        // a raw cid is not a peer id
+        assert!(matches!(
+            PeerID::from_str("bafkreieq5jui4j25lacwomsqgjeswwl3y5zcdrresptwgmfylxo2depppq"),
+            Err(Error::NotPeerIDCodec(0x55))
+        ));
+        assert!(matches!(
+            PeerID::from_str("Qm0OIl"),
+            Err(Error::InvalidPeerID)
+        ));
+        assert!(matches!(
+            PeerID::from_str(&ED25519_ID[..ED25519_ID.len() - 1]),
+            Err(Error::InvalidPeerID)
+        ));
+        assert!(matches!(PeerID::from_str(""), Err(Error::CID(_))));
+        assert!(matches!(
+            PeerID::from_bytes(&[0x12, 0x20, 1, 2]),
+            Err(Error::InvalidPeerID)
+        ));
+        assert!(PeerID::from_bytes(&[]).is_err());
+    }
+
+    #[test]
+    fn test_extract_public_key() {
+        for key_type in [KeyType::Ed25519, KeyType::Secp256k1, KeyType::RSA] {
+            let public_key = PrivateKey::generate(key_type)
+                .unwrap()
+                .public_key()
+                .unwrap();
+            let peer_id = public_key.to_peer_id().unwrap();
+
+            match key_type {
+                // rsa keys are too large to be inlined
+                KeyType::RSA => assert_eq!(peer_id.extract_public_key().unwrap(), None),
+                _ => assert_eq!(peer_id.extract_public_key().unwrap(), Some(public_key)),
+            }
+        }
+
+        // inlined but not a key
+        let peer_id = PeerID::from_bytes(&[0x00, 0x02, 0x08, 0x01]).unwrap();
+        assert!(peer_id.extract_public_key().is_err());
+    }
+
+    #[test]
+    fn test_ord_hash() {
+        let mut ids: Vec<PeerID> = [RSA_ID, ED25519_ID, RSA_ID]
+            .iter()
+            .map(|s| PeerID::from_str(s).unwrap())
+            .collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), 2);
+
+        let set: std::collections::HashSet<PeerID> = ids.into_iter().collect();
+        assert!(set.contains(&PeerID::from_str(ED25519_ID).unwrap()));
     }
 }
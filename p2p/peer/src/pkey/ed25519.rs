@@ -1,54 +1,104 @@
-extern crate openssl;
+use openssl::{pkey, sign};
 
-use openssl::{error::ErrorStack, pkey, sign};
+use crate::Error;
 
-pub struct Ed25519PriKey(pkey::PKey<pkey::Private>);
+const KEY_LEN: usize = 32;
+
+#[derive(Clone)]
+pub(crate) struct Ed25519PriKey(pkey::PKey<pkey::Private>);
 
 impl Ed25519PriKey {
-    pub(crate) fn generate() -> Result<Self, ErrorStack> {
+    pub(crate) fn generate() -> Result<Self, Error> {
         Ok(Self(pkey::PKey::generate_ed25519()?))
     }
 
-    pub(crate) fn from_raw(raw: &[u8]) -> Result<Self, ErrorStack> {
+    /// Imports the 32 bytes seed of a key.
+    pub(crate) fn from_raw(raw: &[u8]) -> Result<Self, Error> {
+        if raw.len() != KEY_LEN {
+            return Err(Error::InvalidKey);
+        }
         let private_key = pkey::PKey::private_key_from_raw_bytes(raw, pkey::Id::ED25519)?;
 
         Ok(Self(private_key))
     }
 
-    pub(crate) fn public_key(&self) -> Result<Ed25519PubKey, ErrorStack> {
+    // the caller checked the key is an ed25519 key
+    pub(crate) fn from_pkey(private_key: pkey::PKey<pkey::Private>) -> Result<Self, Error> {
+        Ok(Self(private_key))
+    }
+
+    pub(crate) fn as_pkey(&self) -> &pkey::PKeyRef<pkey::Private> {
+        &self.0
+    }
+
+    /// Imports the seed followed by the public key, the libp2p protobuf
+    /// encoding, checking the two halves belong together.
+    pub(crate) fn from_keypair(keypair: &[u8]) -> Result<Self, Error> {
+        if keypair.len() != 2 * KEY_LEN {
+            return Err(Error::InvalidKey);
+        }
+        let private_key = Self::from_raw(&keypair[..KEY_LEN])?;
+        if private_key.0.raw_public_key()? != keypair[KEY_LEN..] {
+            return Err(Error::InvalidKey);
+        }
+
+        Ok(private_key)
+    }
+
+    pub(crate) fn to_keypair(&self) -> Result<Vec<u8>, Error> {
+        let mut keypair = self.0.raw_private_key()?;
+        keypair.extend_from_slice(&self.0.raw_public_key()?);
+
+        Ok(keypair)
+    }
+
+    pub(crate) fn public_key(&self) -> Result<Ed25519PubKey, Error> {
         let raw = self.0.raw_public_key()?;
 
         Ed25519PubKey::from_raw(&raw)
     }
 
-    pub(crate) fn raw_key(&self) -> Vec<u8> {
-        self.0.raw_private_key().unwrap()
+    pub(crate) fn raw_key(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.0.raw_private_key()?)
     }
 
-    pub(crate) fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, ErrorStack> {
+    pub(crate) fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, Error> {
         let mut signer = sign::Signer::new_without_digest(self.0.as_ref())?;
 
-        signer.sign_oneshot_to_vec(msg)
+        Ok(signer.sign_oneshot_to_vec(msg)?)
     }
 }
 
-pub struct Ed25519PubKey(pkey::PKey<pkey::Public>);
+#[derive(Clone)]
+pub(crate) struct Ed25519PubKey(pkey::PKey<pkey::Public>);
 
 impl Ed25519PubKey {
-    pub(crate) fn from_raw(raw: &[u8]) -> Result<Self, ErrorStack> {
+    pub(crate) fn from_raw(raw: &[u8]) -> Result<Self, Error> {
+        if raw.len() != KEY_LEN {
+            return Err(Error::InvalidKey);
+        }
+
         Ok(Self(pkey::PKey::public_key_from_raw_bytes(
             raw,
             pkey::Id::ED25519,
         )?))
     }
 
-    pub(crate) fn raw_key(&self) -> Vec<u8> {
-        self.0.raw_public_key().unwrap()
+    pub(crate) fn raw_key(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.0.raw_public_key()?)
     }
 
-    pub(crate) fn verify(&self, msg: &[u8], sig: &[u8]) -> Result<bool, ErrorStack> {
+    pub(crate) fn as_pkey(&self) -> &pkey::PKeyRef<pkey::Public> {
+        &self.0
+    }
+
+    pub(crate) fn verify(&self, msg: &[u8], sig: &[u8]) -> Result<bool, Error> {
         let mut verifier = sign::Verifier::new_without_digest(self.0.as_ref())?;
 
-        verifier.verify_oneshot(sig, msg)
+        Ok(verifier.verify_oneshot(sig, msg)?)
+    }
+
+    pub(crate) fn public_eq(&self, other: &Self) -> bool {
+        self.0.public_eq(&other.0)
     }
 }
@@ -1,52 +1,102 @@
-use openssl::{error::ErrorStack, hash::MessageDigest, pkey, rsa, sign};
+use openssl::{hash::MessageDigest, pkey, rsa, sign};
 
-extern crate openssl;
+use crate::Error;
 
-pub struct RSAPriKey(pkey::PKey<pkey::Private>);
+// the smallest key libp2p accepts
+pub(crate) const MIN_BITS: u32 = 2048;
+
+fn check_bits(bits: u32) -> Result<(), Error> {
+    match bits < MIN_BITS {
+        true => Err(Error::RSAKeyTooSmall(bits)),
+        false => Ok(()),
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct RSAPriKey(pkey::PKey<pkey::Private>);
 
 impl RSAPriKey {
-    pub(crate) fn generate(bits: u32) -> Result<Self, ErrorStack> {
+    pub(crate) fn generate(bits: u32) -> Result<Self, Error> {
+        check_bits(bits)?;
         let private_key = rsa::Rsa::generate(bits)?;
 
         Ok(Self(pkey::PKey::from_rsa(private_key)?))
     }
 
-    pub(crate) fn public_key(&self) -> Result<RSAPubKey, ErrorStack> {
-        let raw = self.0.raw_public_key()?;
+    /// Imports a PKCS#1 DER private key.
+    pub(crate) fn from_raw(raw: &[u8]) -> Result<Self, Error> {
+        let private_key = rsa::Rsa::private_key_from_der(raw).or(Err(Error::InvalidKey))?;
+        check_bits(private_key.size() * 8)?;
+        if !private_key.check_key()? {
+            return Err(Error::InvalidKey);
+        }
+
+        Ok(Self(pkey::PKey::from_rsa(private_key)?))
+    }
+
+    // the caller checked the key is an rsa key
+    pub(crate) fn from_pkey(private_key: pkey::PKey<pkey::Private>) -> Result<Self, Error> {
+        check_bits(private_key.bits())?;
+        if !private_key.rsa()?.check_key()? {
+            return Err(Error::InvalidKey);
+        }
+
+        Ok(Self(private_key))
+    }
+
+    pub(crate) fn as_pkey(&self) -> &pkey::PKeyRef<pkey::Private> {
+        &self.0
+    }
+
+    pub(crate) fn public_key(&self) -> Result<RSAPubKey, Error> {
+        let raw = self.0.public_key_to_der()?;
 
         RSAPubKey::from_raw(&raw)
     }
 
-    pub(crate) fn raw_key(&self) -> Vec<u8> {
-        self.0.raw_private_key().unwrap()
+    pub(crate) fn raw_key(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.0.rsa()?.private_key_to_der()?)
     }
 
-    pub(crate) fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, ErrorStack> {
+    pub(crate) fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, Error> {
         let mut signer = sign::Signer::new(MessageDigest::sha256(), self.0.as_ref())?;
         signer.update(msg)?;
 
-        signer.sign_to_vec()
+        Ok(signer.sign_to_vec()?)
     }
 }
 
-pub struct RSAPubKey(pkey::PKey<pkey::Public>);
+#[derive(Clone)]
+pub(crate) struct RSAPubKey(pkey::PKey<pkey::Public>);
 
 impl RSAPubKey {
-    pub(crate) fn from_raw(raw: &[u8]) -> Result<Self, ErrorStack> {
-        Ok(Self(pkey::PKey::public_key_from_raw_bytes(
-            raw,
-            pkey::Id::RSA,
-        )?))
+    /// Imports a PKIX DER public key.
+    pub(crate) fn from_raw(raw: &[u8]) -> Result<Self, Error> {
+        let public_key = pkey::PKey::public_key_from_der(raw).or(Err(Error::InvalidKey))?;
+        if public_key.id() != pkey::Id::RSA {
+            return Err(Error::InvalidKey);
+        }
+        check_bits(public_key.bits())?;
+
+        Ok(Self(public_key))
     }
 
-    pub(crate) fn raw_key(&self) -> Vec<u8> {
-        self.0.raw_public_key().unwrap()
+    pub(crate) fn raw_key(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.0.public_key_to_der()?)
     }
 
-    pub(crate) fn verify(&self, msg: &[u8], sig: &[u8]) -> Result<bool, ErrorStack> {
+    pub(crate) fn as_pkey(&self) -> &pkey::PKeyRef<pkey::Public> {
+        &self.0
+    }
+
+    pub(crate) fn verify(&self, msg: &[u8], sig: &[u8]) -> Result<bool, Error> {
         let mut verifier = sign::Verifier::new(MessageDigest::sha256(), self.0.as_ref())?;
         verifier.update(msg)?;
 
-        verifier.verify(sig)
+        Ok(verifier.verify(sig)?)
+    }
+
+    pub(crate) fn public_eq(&self, other: &Self) -> bool {
+        self.0.public_eq(&other.0)
     }
 }
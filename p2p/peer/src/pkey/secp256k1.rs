@@ -1,59 +1,126 @@
-extern crate openssl;
+use openssl::{
+    bn::{BigNum, BigNumContext},
+    ec::{EcGroup, EcKey, EcPoint, PointConversionForm},
+    hash::MessageDigest,
+    nid::Nid,
+    pkey, sign,
+};
 
-use openssl::{ec, error::ErrorStack, hash::MessageDigest, nid, pkey, sign};
+use crate::Error;
 
-pub struct Secp256k1PriKey(pkey::PKey<pkey::Private>);
+const KEY_LEN: usize = 32;
+
+fn group() -> Result<EcGroup, Error> {
+    Ok(EcGroup::from_curve_name(Nid::SECP256K1)?)
+}
+
+#[derive(Clone)]
+pub(crate) struct Secp256k1PriKey(pkey::PKey<pkey::Private>);
 
 impl Secp256k1PriKey {
-    pub(crate) fn generate() -> Result<Self, ErrorStack> {
-        let ecgroup = ec::EcGroup::from_curve_name(nid::Nid::SECP256K1)?;
-        let private_key = ec::EcKey::generate(&ecgroup)?;
+    pub(crate) fn generate() -> Result<Self, Error> {
+        let private_key = EcKey::generate(&*group()?)?;
+
+        Ok(Self(pkey::PKey::from_ec_key(private_key)?))
+    }
+
+    /// Imports the 32 bytes big endian secret scalar.
+    pub(crate) fn from_raw(raw: &[u8]) -> Result<Self, Error> {
+        if raw.len() != KEY_LEN {
+            return Err(Error::InvalidKey);
+        }
+
+        let group = group()?;
+        let mut ctx = BigNumContext::new()?;
+        let secret = BigNum::from_slice(raw)?;
+
+        let mut public = EcPoint::new(&group)?;
+        public.mul_generator2(&group, &secret, &mut ctx)?;
+
+        let private_key = EcKey::from_private_components(&group, &secret, &public)?;
+        private_key.check_key().or(Err(Error::InvalidKey))?;
 
         Ok(Self(pkey::PKey::from_ec_key(private_key)?))
     }
 
-    pub(crate) fn from_raw(raw: &[u8]) -> Result<Self, ErrorStack> {
-        let private_key = pkey::PKey::private_key_from_raw_bytes(raw, pkey::Id::EC)?;
+    /// Takes an ec key, which must be on the secp256k1 curve.
+    pub(crate) fn from_pkey(private_key: pkey::PKey<pkey::Private>) -> Result<Self, Error> {
+        let ec_key = private_key.ec_key()?;
+        if ec_key.group().curve_name() != Some(Nid::SECP256K1) {
+            return Err(Error::InvalidKey);
+        }
+        ec_key.check_key().or(Err(Error::InvalidKey))?;
 
         Ok(Self(private_key))
     }
 
-    pub(crate) fn public_key(&self) -> Result<Secp256k1PubKey, ErrorStack> {
-        let raw = self.0.raw_public_key()?;
+    pub(crate) fn as_pkey(&self) -> &pkey::PKeyRef<pkey::Private> {
+        &self.0
+    }
+
+    pub(crate) fn public_key(&self) -> Result<Secp256k1PubKey, Error> {
+        let ec_key = self.0.ec_key()?;
+        let public_key = EcKey::from_public_key(ec_key.group(), ec_key.public_key())?;
 
-        Secp256k1PubKey::from_raw(&raw)
+        Ok(Secp256k1PubKey(pkey::PKey::from_ec_key(public_key)?))
     }
 
-    pub(crate) fn raw_key(&self) -> Vec<u8> {
-        self.0.raw_private_key().unwrap()
+    pub(crate) fn raw_key(&self) -> Result<Vec<u8>, Error> {
+        Ok(self
+            .0
+            .ec_key()?
+            .private_key()
+            .to_vec_padded(KEY_LEN as i32)?)
     }
 
-    pub(crate) fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, ErrorStack> {
-        let mut signer = sign::Signer::new(MessageDigest::sha256(), &self.0.as_ref())?;
+    pub(crate) fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut signer = sign::Signer::new(MessageDigest::sha256(), self.0.as_ref())?;
         signer.update(msg)?;
 
-        signer.sign_to_vec()
+        Ok(signer.sign_to_vec()?)
     }
 }
 
-pub struct Secp256k1PubKey(pkey::PKey<pkey::Public>);
+#[derive(Clone)]
+pub(crate) struct Secp256k1PubKey(pkey::PKey<pkey::Public>);
 
 impl Secp256k1PubKey {
-    pub(crate) fn from_raw(raw: &[u8]) -> Result<Self, ErrorStack> {
-        Ok(Self(pkey::PKey::public_key_from_raw_bytes(
-            raw,
-            pkey::Id::EC,
-        )?))
+    /// Imports a SEC1 encoded point, compressed or not.
+    pub(crate) fn from_raw(raw: &[u8]) -> Result<Self, Error> {
+        let group = group()?;
+        let mut ctx = BigNumContext::new()?;
+
+        let point = EcPoint::from_bytes(&group, raw, &mut ctx).or(Err(Error::InvalidKey))?;
+        let public_key = EcKey::from_public_key(&group, &point)?;
+        public_key.check_key().or(Err(Error::InvalidKey))?;
+
+        Ok(Self(pkey::PKey::from_ec_key(public_key)?))
     }
 
-    pub(crate) fn raw_key(&self) -> Vec<u8> {
-        self.0.raw_public_key().unwrap()
+    /// Exports the compressed SEC1 point.
+    pub(crate) fn raw_key(&self) -> Result<Vec<u8>, Error> {
+        let ec_key = self.0.ec_key()?;
+        let mut ctx = BigNumContext::new()?;
+
+        Ok(ec_key.public_key().to_bytes(
+            ec_key.group(),
+            PointConversionForm::COMPRESSED,
+            &mut ctx,
+        )?)
     }
 
-    pub(crate) fn verify(&self, msg: &[u8], sig: &[u8]) -> Result<bool, ErrorStack> {
+    pub(crate) fn as_pkey(&self) -> &pkey::PKeyRef<pkey::Public> {
+        &self.0
+    }
+
+    pub(crate) fn verify(&self, msg: &[u8], sig: &[u8]) -> Result<bool, Error> {
         let mut verifier = sign::Verifier::new(MessageDigest::sha256(), self.0.as_ref())?;
         verifier.update(msg)?;
 
-        verifier.verify(sig)
+        Ok(verifier.verify(sig)?)
+    }
+
+    pub(crate) fn public_eq(&self, other: &Self) -> bool {
+        self.0.public_eq(&other.0)
     }
 }
@@ -0,0 +1,137 @@
+use varint::Varint;
+
+use crate::{Error, KeyType};
+
+// field 1 is the varint key type and field 2 the length delimited key data,
+// in both the PublicKey and PrivateKey messages
+const TAG_TYPE: u8 = 0x08;
+const TAG_DATA: u8 = 0x12;
+
+// values of the KeyType enum in the libp2p crypto protobuf
+pub(crate) fn key_type_code(key_type: KeyType) -> u64 {
+    match key_type {
+        KeyType::RSA => 0,
+        KeyType::Ed25519 => 1,
+        KeyType::Secp256k1 => 2,
+    }
+}
+
+pub(crate) fn key_type_from_code(code: u64) -> Result<KeyType, Error> {
+    match code {
+        0 => Ok(KeyType::RSA),
+        1 => Ok(KeyType::Ed25519),
+        2 => Ok(KeyType::Secp256k1),
+        // ecdsa and anything newer
+        _ => Err(Error::UnsupportedKeyType(code)),
+    }
+}
+
+fn read_varint(input: &mut &[u8]) -> Result<u64, Error> {
+    let (val, len) = u64::decode_varint(input).ok_or(Error::InvalidKey)?;
+    *input = &input[len..];
+
+    Ok(val)
+}
+
+fn push_varint(output: &mut Vec<u8>, val: u64) {
+    let mut buf = [0u8; 10];
+    let len = val.encode_varint(&mut buf);
+    output.extend_from_slice(&buf[..len]);
+}
+
+/// Encodes a key message, the type always comes first so the encoding is
+/// deterministic as the spec requires.
+pub(crate) fn encode_key(key_type: KeyType, data: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(data.len() + 6);
+
+    output.push(TAG_TYPE);
+    push_varint(&mut output, key_type_code(key_type));
+    output.push(TAG_DATA);
+    push_varint(&mut output, data.len() as u64);
+    output.extend_from_slice(data);
+
+    output
+}
+
+/// Decodes a key message into its type and data, both fields are required
+/// and nothing else may follow.
+pub(crate) fn decode_key(mut input: &[u8]) -> Result<(KeyType, &[u8]), Error> {
+    let mut key_type = None;
+    let mut data = None;
+
+    while !input.is_empty() {
+        let tag = read_varint(&mut input)?;
+        match tag {
+            t if t == TAG_TYPE as u64 && key_type.is_none() => {
+                key_type = Some(read_varint(&mut input)?);
+            }
+            t if t == TAG_DATA as u64 && data.is_none() => {
+                let len = read_varint(&mut input)?;
+                if len > input.len() as u64 {
+                    return Err(Error::InvalidKey);
+                }
+                let (value, rest) = input.split_at(len as usize);
+                data = Some(value);
+                input = rest;
+            }
+            _ => return Err(Error::InvalidKey),
+        }
+    }
+
+    match (key_type, data) {
+        (Some(code), Some(data)) => Ok((key_type_from_code(code)?, data)),
+        _ => Err(Error::InvalidKey),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_key() {
+        let data = [0xaa; 200];
+        let encoded = encode_key(KeyType::RSA, &data);
+
+        // rsa is 0, still written, and the length takes two bytes
+        assert_eq!(encoded[..5], [0x08, 0x00, 0x12, 0xc8, 0x01]);
+        assert_eq!(encoded[5..], data);
+
+        assert_eq!(
+            encode_key(KeyType::Secp256k1, &[1, 2]),
+            [0x08, 0x02, 0x12, 0x02, 0x01, 0x02]
+        );
+    }
+
+    #[test]
+    fn test_decode_key() {
+        let encoded = encode_key(KeyType::Ed25519, &[7; 32]);
+        let (key_type, data) = decode_key(&encoded).unwrap();
+        assert_eq!(key_type, KeyType::Ed25519);
+        assert_eq!(data, [7; 32]);
+
+        // the fields may come in any order
+        let (key_type, data) = decode_key(&[0x12, 0x01, 0xaa, 0x08, 0x02]).unwrap();
+        assert_eq!(key_type, KeyType::Secp256k1);
+        assert_eq!(data, [0xaa]);
+
+        assert!(matches!(
+            decode_key(&[0x08, 0x03, 0x12, 0x00]),
+            Err(Error::UnsupportedKeyType(3))
+        ));
+        for invalid in [
+            &[][..],
+            &[0x08, 0x01],
+            &[0x12, 0x00],
+            &[0x08, 0x01, 0x12, 0x02, 0xaa],
+            &[0x08, 0x01, 0x12, 0x00, 0x08, 0x01],
+            &[0x08, 0x01, 0x12, 0x00, 0x18, 0x00],
+        ] {
+            assert!(
+                matches!(decode_key(invalid), Err(Error::InvalidKey)),
+                "{:?}",
+                invalid
+            );
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::peer_id::PeerID;
+
+// legacy base58btc form for human readable formats, the multihash bytes
+// otherwise
+impl Serialize for PeerID {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+}
+
+struct PeerIDVisitor;
+
+impl de::Visitor<'_> for PeerIDVisitor {
+    type Value = PeerID;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a peer id")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        PeerID::from_str(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        PeerID::from_bytes(v).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for PeerID {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(PeerIDVisitor)
+        } else {
+            deserializer.deserialize_bytes(PeerIDVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC";
+
+    fn peer_id() -> PeerID {
+        PeerID::from_str(ID).unwrap()
+    }
+
+    #[test]
+    fn test_json() {
+        let json = serde_json::to_string(&peer_id()).unwrap();
+        assert_eq!(json, format!("\"{}\"", ID));
+
+        let decoded: PeerID = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, peer_id());
+
+        let err = serde_json::from_str::<PeerID>("\"Qm0OIl\"").unwrap_err();
+        let expected = PeerID::from_str("Qm0OIl").unwrap_err().to_string();
+        assert!(err.to_string().starts_with(&expected), "{}", err);
+    }
+
+    #[test]
+    fn test_binary() {
+        let bytes = bincode::serialize(&peer_id()).unwrap();
+        assert_eq!(&bytes[8..], peer_id().to_bytes());
+
+        let decoded: PeerID = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(decoded, peer_id());
+
+        assert!(bincode::deserialize::<PeerID>(&0u64.to_le_bytes()).is_err());
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::{BTreeMap, HashMap};
+
+use cid::{CidGeneric, CID};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const COUNT: u32 = 10_000;
+
+fn heap_cids() -> Vec<CID> {
+    (0..COUNT)
+        .map(|i| {
+            let hash = multihash::multihash("sha2-256", &i.to_be_bytes());
+            CID::new("cidv1", "raw", hash).unwrap()
+        })
+        .collect()
+}
+
+fn inline_cids() -> Vec<CidGeneric> {
+    heap_cids()
+        .iter()
+        .map(|cid| CidGeneric::try_from(cid).unwrap())
+        .collect()
+}
+
+fn bench_hash_map(c: &mut Criterion) {
+    let heap = heap_cids();
+    let inline = inline_cids();
+
+    let mut group = c.benchmark_group("hash_map");
+    group.bench_function("insert/heap", |b| {
+        b.iter(|| heap.iter().cloned().zip(0..).collect::<HashMap<_, _>>())
+    });
+    group.bench_function("insert/inline", |b| {
+        b.iter(|| inline.iter().copied().zip(0..).collect::<HashMap<_, _>>())
+    });
+
+    let heap_map: HashMap<_, _> = heap.iter().cloned().zip(0..).collect();
+    let inline_map: HashMap<_, _> = inline.iter().copied().zip(0..).collect();
+    group.bench_function("lookup/heap", |b| {
+        b.iter(|| heap.iter().filter_map(|cid| heap_map.get(cid)).count())
+    });
+    group.bench_function("lookup/inline", |b| {
+        b.iter(|| inline.iter().filter_map(|cid| inline_map.get(cid)).count())
+    });
+    group.finish();
+}
+
+fn bench_btree_map(c: &mut Criterion) {
+    let heap = heap_cids();
+    let inline = inline_cids();
+
+    let mut group = c.benchmark_group("btree_map");
+    group.bench_function("insert/heap", |b| {
+        b.iter(|| heap.iter().cloned().zip(0..).collect::<BTreeMap<_, _>>())
+    });
+    group.bench_function("insert/inline", |b| {
+        b.iter(|| inline.iter().copied().zip(0..).collect::<BTreeMap<_, _>>())
+    });
+
+    let heap_map: BTreeMap<_, _> = heap.iter().cloned().zip(0..).collect();
+    let inline_map: BTreeMap<_, _> = inline.iter().copied().zip(0..).collect();
+    group.bench_function("lookup/heap", |b| {
+        b.iter(|| heap.iter().filter_map(|cid| heap_map.get(cid)).count())
+    });
+    group.bench_function("lookup/inline", |b| {
+        b.iter(|| inline.iter().filter_map(|cid| inline_map.get(cid)).count())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_hash_map, bench_btree_map);
+criterion_main!(benches);
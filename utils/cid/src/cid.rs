@@ -0,0 +1,532 @@
+use std::{
+    fmt,
+    io::{self, Read},
+    str::FromStr,
+};
+
+use multibase::{Decoder, Encoder};
+use multihash::split_multihash;
+use varint::Varint;
+
+use crate::Error;
+
+pub(crate) const DAG_PB: u64 = 0x70;
+pub(crate) const SHA2_256: u64 = 0x12;
+
+/// The longest digest a CID may carry, well above any hash function so
+/// identity hashes of small blocks still fit.
+pub const MAX_DIGEST_LEN: usize = 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CID {
+    version: u64,
+    codec: u64,
+    hash: Vec<u8>,
+}
+
+pub(crate) fn read_varint<R: Read>(r: &mut R) -> io::Result<u64> {
+    let mut buf = [0u8; 10];
+
+    for i in 0..buf.len() {
+        r.read_exact(&mut buf[i..=i])?;
+
+        if buf[i] & 0b1000_0000 == 0 {
+            return u64::decode_varint(&buf[..=i])
+                .map(|(val, _)| val)
+                .ok_or_else(|| io::ErrorKind::InvalidData.into());
+        }
+    }
+
+    Err(io::ErrorKind::InvalidData.into())
+}
+
+pub(crate) fn push_varint(output: &mut Vec<u8>, val: u64) {
+    let mut buf = [0u8; 10];
+    let len = val.encode_varint(&mut buf);
+
+    output.extend_from_slice(&buf[..len]);
+}
+
+fn read_digest<R: Read>(r: &mut R, hashtype: u64) -> Result<Vec<u8>, Error> {
+    // the length is checked before it sizes any read
+    let len = read_varint(r)?;
+    if len > MAX_DIGEST_LEN as u64 {
+        return Err(Error::DigestTooLong(len.try_into().unwrap_or(usize::MAX)));
+    }
+
+    let mut hash = Vec::new();
+    push_varint(&mut hash, hashtype);
+    push_varint(&mut hash, len);
+
+    let offset = hash.len();
+    r.take(len).read_to_end(&mut hash)?;
+    if (hash.len() - offset) as u64 != len {
+        return Err(Error::InvalidMultihash);
+    }
+
+    Ok(hash)
+}
+
+pub(crate) fn is_v0_hash(hash: &[u8]) -> bool {
+    hash.len() == 34 && hash[0] == SHA2_256 as u8 && hash[1] == 32
+}
+
+pub(crate) fn name_code(name: &str) -> Result<u64, Error> {
+    multicodec::try_to_code(name).ok_or_else(|| Error::UnknownName(name.to_string()))
+}
+
+/// multicodec has no entry for CIDv0, so it is resolved here, and `cidvN`
+/// names missing from the table are reported as invalid versions.
+pub(crate) fn version_code(version: &str) -> Result<u64, Error> {
+    match version {
+        "cidv0" => Ok(0),
+        _ => name_code(version).map_err(|e| match version.strip_prefix("cidv").map(str::parse) {
+            Some(Ok(version)) => Error::InvalidVersion(version),
+            _ => e,
+        }),
+    }
+}
+
+/// Resolves the version and codec names and checks they can carry `hash`.
+pub(crate) fn check_parts(version: &str, codec: &str, hash: &[u8]) -> Result<(u64, u64), Error> {
+    let version = version_code(version)?;
+    let codec = name_code(codec)?;
+
+    check_codes(version, codec, hash)?;
+
+    Ok((version, codec))
+}
+
+/// Checks `hash` the way the parsers do, so every CID that can be built also
+/// decodes from its bytes.
+pub(crate) fn check_codes(version: u64, codec: u64, hash: &[u8]) -> Result<(), Error> {
+    match version {
+        0 if codec != DAG_PB || !is_v0_hash(hash) => Err(Error::InvalidV0),
+        0 => Ok(()),
+        1 => match split_multihash(hash) {
+            Some((_, digest)) if digest.len() > MAX_DIGEST_LEN => {
+                Err(Error::DigestTooLong(digest.len()))
+            }
+            Some(_) => Ok(()),
+            None => Err(Error::InvalidMultihash),
+        },
+        _ => Err(Error::InvalidVersion(version)),
+    }
+}
+
+pub(crate) fn write_bytes(version: u64, codec: u64, hash: &[u8]) -> Vec<u8> {
+    if version == 0 {
+        return hash.to_vec();
+    }
+
+    let mut output = Vec::with_capacity(
+        varint::required_encode_space(version) + varint::required_encode_space(codec) + hash.len(),
+    );
+
+    push_varint(&mut output, version);
+    push_varint(&mut output, codec);
+    output.extend_from_slice(hash);
+
+    output
+}
+
+pub(crate) fn encode_bytes<T: multibase::Encoder>(version: u64, codec: u64, hash: &[u8]) -> String {
+    if version == 0 {
+        let mut encoded = multibase::Base58Bitcoin::encode(hash);
+        encoded.remove(0);
+
+        return encoded;
+    }
+
+    T::encode(&write_bytes(version, codec, hash))
+}
+
+impl CID {
+    /// Creates a CID, `version` is either `cidv0` or `cidv1`.
+    ///
+    /// A CIDv0 can only carry a dag-pb block hashed with sha2-256.
+    pub fn new(version: &str, codec: &str, hash: Vec<u8>) -> Result<Self, Error> {
+        let (version, codec) = check_parts(version, codec, &hash)?;
+
+        Ok(Self {
+            version,
+            codec,
+            hash,
+        })
+    }
+
+    pub(crate) fn from_parts(version: u64, codec: u64, hash: Vec<u8>) -> Self {
+        Self {
+            version,
+            codec,
+            hash,
+        }
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn codec(&self) -> u64 {
+        self.codec
+    }
+
+    /// Returns the multihash of the CID.
+    pub fn hash(&self) -> &[u8] {
+        &self.hash
+    }
+
+    pub fn to_v0(&self) -> Result<Self, Error> {
+        if self.codec != DAG_PB || !is_v0_hash(&self.hash) {
+            return Err(Error::InvalidV0);
+        }
+
+        Ok(Self {
+            version: 0,
+            ..self.clone()
+        })
+    }
+
+    pub fn to_v1(&self) -> Self {
+        Self {
+            version: 1,
+            ..self.clone()
+        }
+    }
+
+    /// Reads a binary CID from `r`, consuming exactly the bytes of one CID.
+    pub fn read_bytes<R: Read>(mut r: R) -> Result<Self, Error> {
+        let version = read_varint(&mut r)?;
+
+        // a CIDv0 is a bare sha2-256 multihash, so its first byte is the hash type
+        if version == SHA2_256 {
+            let hash = read_digest(&mut r, SHA2_256)?;
+            if !is_v0_hash(&hash) {
+                return Err(Error::InvalidMultihash);
+            }
+
+            return Ok(Self {
+                version: 0,
+                codec: DAG_PB,
+                hash,
+            });
+        }
+
+        if version != 1 {
+            return Err(Error::InvalidVersion(version));
+        }
+
+        let codec = read_varint(&mut r)?;
+        if multicodec::try_to_type(codec).is_none() {
+            return Err(Error::UnknownCodec(codec));
+        }
+
+        let hashtype = read_varint(&mut r)?;
+        let hash = read_digest(&mut r, hashtype)?;
+
+        Ok(Self {
+            version,
+            codec,
+            hash,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        write_bytes(self.version, self.codec, &self.hash)
+    }
+
+    /// Encodes the CID with the multibase `T`.
+    ///
+    /// A CIDv0 ignores `T` and is always base58btc without a multibase prefix.
+    pub fn encode<T: multibase::Encoder>(&self) -> String {
+        encode_bytes::<T>(self.version, self.codec, &self.hash)
+    }
+}
+
+impl TryFrom<&[u8]> for CID {
+    type Error = Error;
+
+    fn try_from(mut input: &[u8]) -> Result<Self, Self::Error> {
+        let cid = Self::read_bytes(&mut input).map_err(|e| match e {
+            Error::Io(e) if e.kind() == io::ErrorKind::UnexpectedEof => Error::InvalidLength,
+            e => e,
+        })?;
+
+        if !input.is_empty() {
+            return Err(Error::InvalidLength);
+        }
+
+        Ok(cid)
+    }
+}
+
+impl fmt::Display for CID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encode::<multibase::Base32>())
+    }
+}
+
+impl FromStr for CID {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = if s.len() == 46 && s.starts_with("Qm") {
+            multibase::Base58Bitcoin::decode(&format!("z{}", s))
+        } else {
+            multibase::multibase_decode(s)
+        };
+
+        if bytes.is_empty() {
+            return Err(Error::InvalidMultibase);
+        }
+
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cidencode() {
+        let hash = multihash::multihash("sha2-256", b"beep boop");
+        let cid = CID::new("cidv1", "raw", hash).unwrap();
+
+        println!("{}", cid.encode::<multibase::Base32>())
+    }
+
+    #[test]
+    fn test_cid_from_str() {
+        let v1 = "bafkreieq5jui4j25lacwomsqgjeswwl3y5zcdrresptwgmfylxo2depppq";
+        let cid = CID::from_str(v1).unwrap();
+        assert_eq!(cid.encode::<multibase::Base32>(), v1);
+        assert_eq!(cid.to_bytes()[..4], [0x01, 0x55, 0x12, 0x20]);
+
+        let v0 = "QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n";
+        let cid = CID::from_str(v0).unwrap();
+        assert_eq!(cid.encode::<multibase::Base58Bitcoin>(), v0);
+
+        let base58 = CID::new(
+            "cidv1",
+            "raw",
+            multihash::multihash("sha2-256", b"beep boop"),
+        )
+        .unwrap()
+        .encode::<multibase::Base58Bitcoin>();
+        assert!(CID::from_str(&base58).is_ok());
+
+        assert!(matches!(
+            CID::from_str("!bafkreieq5jui4j25lacwomsqgjeswwl3y5zcdrresptwgmfylxo2depppq"),
+            Err(Error::InvalidMultibase)
+        ));
+        assert!(matches!(
+            CID::from_str(&v1[..v1.len() - 8]),
+            Err(Error::InvalidMultihash)
+        ));
+    }
+
+    #[test]
+    fn test_cid_try_from_bytes() {
+        let hash = multihash::multihash("sha2-256", b"beep boop");
+
+        let mut bytes = vec![0x01, 0x55];
+        bytes.extend_from_slice(&hash);
+        assert_eq!(CID::try_from(bytes.as_slice()).unwrap().to_bytes(), bytes);
+
+        bytes[0] = 0x02;
+        assert!(matches!(
+            CID::try_from(bytes.as_slice()),
+            Err(Error::InvalidVersion(2))
+        ));
+
+        bytes[0] = 0x01;
+        bytes[1] = 0x7f;
+        assert!(matches!(
+            CID::try_from(bytes.as_slice()),
+            Err(Error::UnknownCodec(0x7f))
+        ));
+
+        bytes[1] = 0x55;
+        bytes.push(0);
+        assert!(matches!(
+            CID::try_from(bytes.as_slice()),
+            Err(Error::InvalidLength)
+        ));
+
+        assert!(matches!(
+            CID::try_from(&hash[..20]),
+            Err(Error::InvalidMultihash)
+        ));
+    }
+
+    #[test]
+    fn test_cid_read_bytes() {
+        let first = CID::new("cidv1", "raw", multihash::multihash("sha2-256", b"beep")).unwrap();
+        let second =
+            CID::new("cidv1", "dag-cbor", multihash::multihash("blake3", b"boop")).unwrap();
+
+        let mut stream = first.to_bytes();
+        stream.extend_from_slice(&second.to_bytes());
+
+        let mut r = stream.as_slice();
+        assert_eq!(
+            CID::read_bytes(&mut r).unwrap().to_bytes(),
+            first.to_bytes()
+        );
+        assert_eq!(
+            CID::read_bytes(&mut r).unwrap().to_bytes(),
+            second.to_bytes()
+        );
+        assert!(matches!(CID::read_bytes(&mut r), Err(Error::Io(_))));
+    }
+
+    #[test]
+    fn test_cid_digest_len() {
+        // a varint length of 2^62 must fail before anything is allocated
+        let mut bytes = vec![0x01, 0x55, 0x00];
+        push_varint(&mut bytes, 1 << 62);
+        assert!(matches!(
+            CID::try_from(bytes.as_slice()),
+            Err(Error::DigestTooLong(_))
+        ));
+
+        let mut bytes = vec![0x01, 0x55, 0x00];
+        push_varint(&mut bytes, MAX_DIGEST_LEN as u64 + 1);
+        bytes.resize(bytes.len() + MAX_DIGEST_LEN + 1, 0);
+        assert!(matches!(
+            CID::try_from(bytes.as_slice()),
+            Err(Error::DigestTooLong(1025))
+        ));
+
+        let mut hash = vec![0x00];
+        push_varint(&mut hash, MAX_DIGEST_LEN as u64);
+        hash.resize(hash.len() + MAX_DIGEST_LEN, 0);
+        let cid = CID::new("cidv1", "raw", hash.clone()).unwrap();
+        assert_eq!(CID::try_from(cid.to_bytes().as_slice()).unwrap(), cid);
+
+        hash[1..3].copy_from_slice(&[0x81, 0x08]);
+        hash.push(0);
+        assert!(matches!(
+            CID::new("cidv1", "raw", hash),
+            Err(Error::DigestTooLong(1025))
+        ));
+    }
+
+    #[test]
+    fn test_cid_new_checks_multihash() {
+        for hash in [
+            vec![],
+            vec![1, 2, 3],
+            vec![0x12, 0x20, 0xaa],
+            vec![0x12, 0x01, 1, 2],
+        ] {
+            assert!(matches!(
+                CID::new("cidv1", "raw", hash.clone()),
+                Err(Error::InvalidMultihash)
+            ));
+            assert!(matches!(
+                crate::CidGeneric::<68>::new("cidv1", "raw", &hash),
+                Err(Error::InvalidMultihash)
+            ));
+        }
+
+        // whatever new accepts decodes back
+        let cid = CID::new("cidv1", "raw", vec![0x00, 0x03, 1, 2, 3]).unwrap();
+        assert_eq!(CID::try_from(cid.to_bytes().as_slice()).unwrap(), cid);
+    }
+
+    #[test]
+    fn test_cid_v0() {
+        let v0 = "QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n";
+        let v1 = "bafybeihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku";
+
+        let cid = CID::from_str(v0).unwrap();
+        assert_eq!(cid.to_bytes()[..2], [0x12, 0x20]);
+        assert_eq!(cid.encode::<multibase::Base32>(), v0);
+        assert_eq!(cid.to_v1().encode::<multibase::Base32>(), v1);
+
+        let cid = CID::from_str(v1).unwrap();
+        assert_eq!(cid.to_v0().unwrap().encode::<multibase::Base32>(), v0);
+
+        let hash = multihash::multihash("sha2-256", b"beep boop");
+        assert!(CID::new("cidv0", "dag-pb", hash.clone()).is_ok());
+        assert!(matches!(
+            CID::new("cidv0", "raw", hash.clone()),
+            Err(Error::InvalidV0)
+        ));
+        assert!(matches!(
+            CID::new("cidv2", "dag-pb", hash),
+            Err(Error::InvalidVersion(2))
+        ));
+
+        let hash = multihash::multihash("blake3", b"beep boop");
+        assert!(matches!(
+            CID::new("cidv0", "dag-pb", hash.clone()),
+            Err(Error::InvalidV0)
+        ));
+        assert!(matches!(
+            CID::new("cidv1", "dag-pb", hash).unwrap().to_v0(),
+            Err(Error::InvalidV0)
+        ));
+    }
+
+    #[test]
+    fn test_cid_unknown_names() {
+        let hash = multihash::multihash("sha2-256", b"beep boop");
+
+        assert!(matches!(
+            CID::new("cidv9", "raw", hash.clone()),
+            Err(Error::InvalidVersion(9))
+        ));
+        assert!(matches!(
+            CID::new("nope", "raw", hash.clone()),
+            Err(Error::UnknownName(name)) if name == "nope"
+        ));
+        assert!(matches!(
+            CID::new("cidv1", "nope", hash.clone()),
+            Err(Error::UnknownName(name)) if name == "nope"
+        ));
+        // a known name that is not a version
+        assert!(matches!(
+            CID::new("raw", "raw", hash.clone()),
+            Err(Error::InvalidVersion(0x55))
+        ));
+        assert!(matches!(
+            crate::CidGeneric::<64>::new("cidv1", "nope", &hash),
+            Err(Error::UnknownName(_))
+        ));
+    }
+
+    #[test]
+    fn test_cid_traits() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let v0 = CID::from_str("QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n").unwrap();
+        let v1 = v0.to_v1();
+
+        assert_eq!(
+            v0.to_string(),
+            "QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n"
+        );
+        assert_eq!(
+            v1.to_string(),
+            "bafybeihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"
+        );
+        assert_eq!(v1.to_string().parse::<CID>().unwrap(), v1);
+
+        assert_eq!(v1.version(), 1);
+        assert_eq!(v1.codec(), DAG_PB);
+        assert_eq!(v1.hash(), v0.hash());
+        assert_ne!(v0, v1);
+        assert!(v0 < v1);
+
+        let mut hashed = HashMap::new();
+        hashed.insert(v1.clone(), 1);
+        assert_eq!(hashed.get(&v0.to_v1()), Some(&1));
+
+        let mut ordered = BTreeMap::new();
+        ordered.insert(v0.clone(), 0);
+        assert_eq!(ordered.get(&v1.to_v0().unwrap()), Some(&0));
+    }
+}
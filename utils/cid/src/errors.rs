@@ -0,0 +1,50 @@
+use std::{error, fmt, io};
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidMultibase,
+    InvalidVersion(u64),
+    InvalidV0,
+    UnknownCodec(u64),
+    UnknownName(String),
+    InvalidMultihash,
+    InvalidLength,
+    CodecNotAllowed(u64),
+    HashNotAllowed(u64),
+    UnsupportedHash(u64),
+    DigestTooShort(usize),
+    DigestTooLong(usize),
+    UnknownDirective(char),
+    IncompleteDirective,
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMultibase => write!(f, "invalid multibase encoding"),
+            Self::InvalidVersion(v) => write!(f, "invalid cid version {}", v),
+            Self::InvalidV0 => write!(f, "cidv0 requires dag-pb and a sha2-256 multihash"),
+            Self::UnknownCodec(v) => write!(f, "unknown codec 0x{:x}", v),
+            Self::UnknownName(v) => write!(f, "unknown multicodec name {}", v),
+            Self::InvalidMultihash => write!(f, "invalid multihash"),
+            Self::InvalidLength => write!(f, "invalid cid length"),
+            Self::CodecNotAllowed(v) => write!(f, "codec 0x{:x} is not allowed", v),
+            Self::HashNotAllowed(v) => write!(f, "hash function 0x{:x} is not allowed", v),
+            Self::UnsupportedHash(v) => write!(f, "hash function 0x{:x} is not supported", v),
+            Self::DigestTooShort(len) => write!(f, "digest of {} bytes is too short", len),
+            Self::DigestTooLong(len) => write!(f, "digest of {} bytes is too long", len),
+            Self::UnknownDirective(c) => write!(f, "unknown format directive %{}", c),
+            Self::IncompleteDirective => write!(f, "format ends with an incomplete directive"),
+            Self::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
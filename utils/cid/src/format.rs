@@ -0,0 +1,146 @@
+use std::fmt::Write;
+
+use multihash::split_multihash;
+
+use crate::{Error, CID};
+
+fn code_name(code: u64) -> String {
+    match multicodec::try_to_type(code) {
+        Some(name) => name.to_string(),
+        _ => format!("0x{:x}", code),
+    }
+}
+
+fn strip_prefix(encoded: String) -> String {
+    encoded.chars().skip(1).collect()
+}
+
+/// Renders `cid` following `template`, the same way as `ipfs cid format`.
+///
+/// `base` is the multibase name used by `%s`, `%m` and `%d`. Directives:
+///
+/// - `%%` a literal `%`
+/// - `%b` and `%B` the multibase name and prefix
+/// - `%v` and `%V` the cid version as `cidv1` and `1`
+/// - `%c` and `%C` the codec name and code
+/// - `%h` and `%H` the hash function name and code
+/// - `%L` the digest length
+/// - `%m` and `%M` the multihash, with and without multibase prefix
+/// - `%d` and `%D` the digest, with and without multibase prefix
+/// - `%s` and `%S` the cid, with and without multibase prefix
+/// - `%P` the cid prefix, `%v-%c-%h-%L`
+pub fn format(cid: &CID, template: &str, base: &str) -> Result<String, Error> {
+    let prefix = multibase::base_prefix(base).ok_or(Error::InvalidMultibase)?;
+    let (hashtype, digest) = split_multihash(cid.hash()).ok_or(Error::InvalidMultihash)?;
+
+    let mut output = String::with_capacity(template.len());
+
+    let mut chars = template.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            output.push(c);
+            continue;
+        }
+
+        let directive = chars.next().ok_or(Error::IncompleteDirective)?;
+        match directive {
+            '%' => output.push('%'),
+            'b' => output.push_str(base),
+            'B' => output.push(prefix),
+            'v' => write!(output, "cidv{}", cid.version()).unwrap(),
+            'V' => write!(output, "{}", cid.version()).unwrap(),
+            'c' => output.push_str(&code_name(cid.codec())),
+            'C' => write!(output, "{}", cid.codec()).unwrap(),
+            'h' => output.push_str(&code_name(hashtype)),
+            'H' => write!(output, "{}", hashtype).unwrap(),
+            'L' => write!(output, "{}", digest.len()).unwrap(),
+            'm' => output.push_str(&multibase::multibase_encode(prefix, cid.hash())),
+            'M' => output.push_str(&strip_prefix(multibase::multibase_encode(
+                prefix,
+                cid.hash(),
+            ))),
+            'd' => output.push_str(&multibase::multibase_encode(prefix, digest)),
+            'D' => output.push_str(&strip_prefix(multibase::multibase_encode(prefix, digest))),
+            's' | 'S' if cid.version() == 0 => {
+                output.push_str(&cid.encode::<multibase::Base58Bitcoin>())
+            }
+            's' => output.push_str(&multibase::multibase_encode(prefix, &cid.to_bytes())),
+            'S' => output.push_str(&strip_prefix(multibase::multibase_encode(
+                prefix,
+                &cid.to_bytes(),
+            ))),
+            'P' => write!(
+                output,
+                "cidv{}-{}-{}-{}",
+                cid.version(),
+                code_name(cid.codec()),
+                code_name(hashtype),
+                digest.len()
+            )
+            .unwrap(),
+
+            _ => return Err(Error::UnknownDirective(directive)),
+        }
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let v0: CID = "QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n"
+            .parse()
+            .unwrap();
+        let v1 = v0.to_v1();
+
+        assert_eq!(
+            format(&v1, "%P %b %B %v %V", "base32").unwrap(),
+            "cidv1-dag-pb-sha2-256-32 base32 b cidv1 1"
+        );
+        assert_eq!(
+            format(&v1, "%c %C %h %H %L 100%%", "base32").unwrap(),
+            "dag-pb 112 sha2-256 18 32 100%"
+        );
+        assert_eq!(
+            format(&v1, "%s", "base32").unwrap(),
+            "bafybeihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"
+        );
+        assert_eq!(
+            format(&v1, "%S", "base32").unwrap(),
+            "afybeihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"
+        );
+        assert_eq!(
+            format(&v0, "%s %S", "base32").unwrap(),
+            "QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n"
+        );
+        assert_eq!(
+            format(&v0, "%M", "base58btc").unwrap(),
+            "QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n"
+        );
+        assert_eq!(
+            format(&v0, "%d", "base16").unwrap(),
+            multibase::multibase_encode('f', &v0.hash()[2..])
+        );
+        assert_eq!(
+            format(&v0, "%D", "base16").unwrap(),
+            format(&v0, "%d", "base16").unwrap()[1..]
+        );
+
+        assert!(matches!(
+            format(&v1, "%x", "base32"),
+            Err(Error::UnknownDirective('x'))
+        ));
+        assert!(matches!(
+            format(&v1, "%s%", "base32"),
+            Err(Error::IncompleteDirective)
+        ));
+        assert!(matches!(
+            format(&v1, "%s", "base1"),
+            Err(Error::InvalidMultibase)
+        ));
+    }
+}
@@ -0,0 +1,214 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    io::Read,
+    str::FromStr,
+};
+
+use crate::{check_parts, encode_bytes, is_v0_hash, write_bytes, Error, CID, DAG_PB};
+
+/// A CID whose multihash is stored inline in a buffer of `S` bytes.
+///
+/// Unlike [`CID`] it never allocates and is `Copy`, which keeps large
+/// in-memory indexes cheap. `S` bounds the whole multihash, its code and
+/// length included, and longer multihashes are rejected. The default fits a
+/// 64 bytes digest under any code of up to 3 varint bytes, blake2b-512
+/// included.
+#[derive(Clone, Copy)]
+pub struct CidGeneric<const S: usize = 68> {
+    version: u64,
+    codec: u64,
+    len: usize,
+    hash: [u8; S],
+}
+
+impl<const S: usize> CidGeneric<S> {
+    /// Creates a CID, `version` is either `cidv0` or `cidv1`.
+    ///
+    /// A CIDv0 can only carry a dag-pb block hashed with sha2-256.
+    pub fn new(version: &str, codec: &str, hash: &[u8]) -> Result<Self, Error> {
+        let (version, codec) = check_parts(version, codec, hash)?;
+
+        Self::from_parts(version, codec, hash)
+    }
+
+    fn from_parts(version: u64, codec: u64, hash: &[u8]) -> Result<Self, Error> {
+        if hash.len() > S {
+            return Err(Error::InvalidLength);
+        }
+
+        let mut buf = [0u8; S];
+        buf[..hash.len()].copy_from_slice(hash);
+
+        Ok(Self {
+            version,
+            codec,
+            len: hash.len(),
+            hash: buf,
+        })
+    }
+
+    /// Reads a binary CID from `r`, consuming exactly the bytes of one CID.
+    pub fn read_bytes<R: Read>(r: R) -> Result<Self, Error> {
+        Self::try_from(&CID::read_bytes(r)?)
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn codec(&self) -> u64 {
+        self.codec
+    }
+
+    /// Returns the multihash of the CID.
+    pub fn hash(&self) -> &[u8] {
+        &self.hash[..self.len]
+    }
+
+    pub fn to_v0(&self) -> Result<Self, Error> {
+        if self.codec != DAG_PB || !is_v0_hash(self.hash()) {
+            return Err(Error::InvalidV0);
+        }
+
+        Ok(Self {
+            version: 0,
+            ..*self
+        })
+    }
+
+    pub fn to_v1(&self) -> Self {
+        Self {
+            version: 1,
+            ..*self
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        write_bytes(self.version, self.codec, self.hash())
+    }
+
+    /// Encodes the CID with the multibase `T`.
+    ///
+    /// A CIDv0 ignores `T` and is always base58btc without a multibase prefix.
+    pub fn encode<T: multibase::Encoder>(&self) -> String {
+        encode_bytes::<T>(self.version, self.codec, self.hash())
+    }
+}
+
+impl<const S: usize> TryFrom<&CID> for CidGeneric<S> {
+    type Error = Error;
+
+    fn try_from(cid: &CID) -> Result<Self, Self::Error> {
+        Self::from_parts(cid.version(), cid.codec(), cid.hash())
+    }
+}
+
+impl<const S: usize> From<&CidGeneric<S>> for CID {
+    fn from(cid: &CidGeneric<S>) -> Self {
+        CID::from_parts(cid.version, cid.codec, cid.hash().to_vec())
+    }
+}
+
+impl<const S: usize> TryFrom<&[u8]> for CidGeneric<S> {
+    type Error = Error;
+
+    fn try_from(input: &[u8]) -> Result<Self, Self::Error> {
+        Self::try_from(&CID::try_from(input)?)
+    }
+}
+
+impl<const S: usize> FromStr for CidGeneric<S> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(&CID::from_str(s)?)
+    }
+}
+
+impl<const S: usize> fmt::Display for CidGeneric<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encode::<multibase::Base32>())
+    }
+}
+
+impl<const S: usize> fmt::Debug for CidGeneric<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CidGeneric")
+            .field("version", &self.version)
+            .field("codec", &self.codec)
+            .field("hash", &self.hash())
+            .finish()
+    }
+}
+
+impl<const S: usize> PartialEq for CidGeneric<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version && self.codec == other.codec && self.hash() == other.hash()
+    }
+}
+
+impl<const S: usize> Eq for CidGeneric<S> {}
+
+impl<const S: usize> Hash for CidGeneric<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.version.hash(state);
+        self.codec.hash(state);
+        self.hash().hash(state);
+    }
+}
+
+impl<const S: usize> PartialOrd for CidGeneric<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const S: usize> Ord for CidGeneric<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.version, self.codec, self.hash()).cmp(&(other.version, other.codec, other.hash()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cid_generic() {
+        let v0 = "QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n";
+        let v1 = "bafybeihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku";
+
+        let cid: CidGeneric = v0.parse().unwrap();
+        let copied = cid;
+        assert_eq!(cid, copied);
+        assert_eq!(cid.to_string(), v0);
+        assert_eq!(cid.to_v1().to_string(), v1);
+        assert_eq!(CidGeneric::from_str(v1).unwrap().to_v0().unwrap(), cid);
+
+        let heap = CID::from_str(v1).unwrap();
+        let inline: CidGeneric = CidGeneric::try_from(&heap).unwrap();
+        assert_eq!(inline.to_bytes(), heap.to_bytes());
+        assert_eq!(CID::from(&inline), heap);
+        assert_eq!(
+            CidGeneric::try_from(heap.to_bytes().as_slice()).unwrap(),
+            inline
+        );
+
+        // 64 bytes digests of sha2-512 and of blake2b-512, whose code takes
+        // 3 varint bytes
+        for prefix in [&[0x13, 0x40][..], &[0xc0, 0xe4, 0x02, 0x40]] {
+            let mut hash = prefix.to_vec();
+            hash.extend_from_slice(&[0xab; 64]);
+
+            let cid: CidGeneric = CidGeneric::new("cidv1", "raw", &hash).unwrap();
+            assert_eq!(cid.hash(), hash);
+            assert_eq!(CidGeneric::from_str(&cid.to_string()).unwrap(), cid);
+            assert!(matches!(
+                CidGeneric::<64>::new("cidv1", "raw", &hash),
+                Err(Error::InvalidLength)
+            ));
+        }
+    }
+}
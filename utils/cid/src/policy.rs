@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+
+use multihash::split_multihash;
+
+use crate::{CidGeneric, Error, CID};
+
+const IDENTITY: u64 = 0x00;
+
+/// Rules a CID received from an untrusted peer has to satisfy.
+///
+/// The default matches go-verifcid: only cryptographic hash functions,
+/// digests between 20 and 128 bytes and identity hashes up to 128 bytes.
+#[derive(Debug, Clone)]
+pub struct CidPolicy {
+    pub allowed_hashes: HashSet<u64>,
+    /// `None` allows every codec of the multicodec table.
+    pub allowed_codecs: Option<HashSet<u64>>,
+    pub min_digest_len: usize,
+    pub max_digest_len: usize,
+    pub max_identity_len: usize,
+}
+
+impl Default for CidPolicy {
+    fn default() -> Self {
+        let mut allowed_hashes = HashSet::from([
+            IDENTITY, // identity
+            0x12,     // sha2-256
+            0x13,     // sha2-512
+            0x14,     // sha3-512
+            0x15,     // sha3-384
+            0x16,     // sha3-256
+            0x17,     // sha3-224
+            0x19,     // shake-256
+            0x1a,     // keccak-224
+            0x1b,     // keccak-256
+            0x1c,     // keccak-384
+            0x1d,     // keccak-512
+            0x1e,     // blake3
+            0x56,     // dbl-sha2-256
+            0x1012,   // sha2-256-trunc254-padded
+            0x1100,   // x11
+            0xb401,   // poseidon-bls12_381-a2-fc1
+        ]);
+        // blake2b-160 to blake2b-512 and blake2s-160 to blake2s-256
+        allowed_hashes.extend(0xb214..=0xb240);
+        allowed_hashes.extend(0xb254..=0xb260);
+
+        Self {
+            allowed_hashes,
+            allowed_codecs: None,
+            min_digest_len: 20,
+            max_digest_len: 128,
+            max_identity_len: 128,
+        }
+    }
+}
+
+impl CidPolicy {
+    fn check(&self, codec: u64, hash: &[u8]) -> Result<(), Error> {
+        match &self.allowed_codecs {
+            Some(codecs) if !codecs.contains(&codec) => return Err(Error::CodecNotAllowed(codec)),
+            None if multicodec::try_to_type(codec).is_none() => {
+                return Err(Error::UnknownCodec(codec))
+            }
+            _ => {}
+        }
+
+        let (hashtype, digest) = split_multihash(hash).ok_or(Error::InvalidMultihash)?;
+        if !self.allowed_hashes.contains(&hashtype) {
+            return Err(Error::HashNotAllowed(hashtype));
+        }
+
+        if hashtype == IDENTITY {
+            if digest.len() > self.max_identity_len {
+                return Err(Error::DigestTooLong(digest.len()));
+            }
+        } else if digest.len() < self.min_digest_len {
+            return Err(Error::DigestTooShort(digest.len()));
+        } else if digest.len() > self.max_digest_len {
+            return Err(Error::DigestTooLong(digest.len()));
+        }
+
+        Ok(())
+    }
+}
+
+impl CID {
+    /// Checks the CID against `policy`, any CID accepted from the network
+    /// should pass through here before it is used.
+    pub fn validate(&self, policy: &CidPolicy) -> Result<(), Error> {
+        policy.check(self.codec(), self.hash())
+    }
+}
+
+impl<const S: usize> CidGeneric<S> {
+    /// Checks the CID against `policy`, see [`CID::validate`].
+    pub fn validate(&self, policy: &CidPolicy) -> Result<(), Error> {
+        policy.check(self.codec(), self.hash())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(hash: Vec<u8>) -> CID {
+        CID::new("cidv1", "raw", hash).unwrap()
+    }
+
+    #[test]
+    fn test_default_policy() {
+        let policy = CidPolicy::default();
+
+        for hashtype in ["sha2-256", "blake3", "keccak-256", "blake2b-256"] {
+            let cid = raw(multihash::multihash(hashtype, b"beep boop"));
+            assert!(cid.validate(&policy).is_ok(), "{}", hashtype);
+        }
+
+        let cid = raw(multihash::multihash("sha1", b"beep boop"));
+        assert!(matches!(
+            cid.validate(&policy),
+            Err(Error::HashNotAllowed(0x11))
+        ));
+
+        let cid = raw(multihash::multihash("blake2b-8", b"beep boop"));
+        assert!(matches!(
+            cid.validate(&policy),
+            Err(Error::HashNotAllowed(0xb201))
+        ));
+
+        let cid = raw(vec![0x12, 0x01, 0xff]);
+        assert!(matches!(
+            cid.validate(&policy),
+            Err(Error::DigestTooShort(1))
+        ));
+
+        // a truncated multihash can't even make a CID
+        assert!(matches!(
+            CID::new("cidv1", "raw", vec![0x12, 0x02, 0xff]),
+            Err(Error::InvalidMultihash)
+        ));
+    }
+
+    #[test]
+    fn test_identity_policy() {
+        let mut policy = CidPolicy::default();
+
+        let cid = raw(vec![0x00, 0x03, b'a', b'b', b'c']);
+        assert!(cid.validate(&policy).is_ok());
+
+        let mut hash = vec![0x00, 0x81, 0x01];
+        hash.resize(hash.len() + 129, b'a');
+        let cid = raw(hash);
+        assert!(matches!(
+            cid.validate(&policy),
+            Err(Error::DigestTooLong(129))
+        ));
+
+        policy.max_identity_len = 256;
+        assert!(cid.validate(&policy).is_ok());
+    }
+
+    #[test]
+    fn test_codec_policy() {
+        let policy = CidPolicy {
+            allowed_codecs: Some(HashSet::from([0x70])),
+            ..Default::default()
+        };
+
+        let hash = multihash::multihash("sha2-256", b"beep boop");
+        let cid = CID::new("cidv1", "dag-pb", hash.clone()).unwrap();
+        assert!(cid.validate(&policy).is_ok());
+        assert!(CidGeneric::<64>::try_from(&cid)
+            .unwrap()
+            .validate(&policy)
+            .is_ok());
+
+        assert!(matches!(
+            raw(hash).validate(&policy),
+            Err(Error::CodecNotAllowed(0x55))
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+use std::io;
+
+use multihash::split_multihash;
+
+use crate::{
+    check_codes, name_code, push_varint, read_varint, version_code, Error, CID, DAG_PB, SHA2_256,
+};
+
+/// Everything about a CID except the digest, used to hash many blocks the
+/// same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Prefix {
+    pub version: u64,
+    pub codec: u64,
+    pub mh_type: u64,
+    pub mh_len: usize,
+}
+
+impl Prefix {
+    /// Creates a prefix producing the full digest of `mh_type`.
+    pub fn new(version: &str, codec: &str, mh_type: &str) -> Result<Self, Error> {
+        let version = version_code(version)?;
+        let codec = name_code(codec)?;
+        let mh_type = name_code(mh_type)?;
+        let mh_len = multihash::try_hash_len(mh_type).ok_or(Error::UnsupportedHash(mh_type))?;
+
+        let prefix = Self {
+            version,
+            codec,
+            mh_type,
+            mh_len,
+        };
+        prefix.check()?;
+
+        Ok(prefix)
+    }
+
+    fn check(&self) -> Result<(), Error> {
+        match self.version {
+            0 if self.codec != DAG_PB || self.mh_type != SHA2_256 || self.mh_len != 32 => {
+                Err(Error::InvalidV0)
+            }
+            0 | 1 => Ok(()),
+            _ => Err(Error::InvalidVersion(self.version)),
+        }
+    }
+
+    /// Hashes `data` and returns its CID.
+    pub fn sum(&self, data: &[u8]) -> Result<CID, Error> {
+        let name =
+            multicodec::try_to_type(self.mh_type).ok_or(Error::UnsupportedHash(self.mh_type))?;
+        let full_len =
+            multihash::try_hash_len(self.mh_type).ok_or(Error::UnsupportedHash(self.mh_type))?;
+        if self.mh_len > full_len {
+            return Err(Error::DigestTooLong(self.mh_len));
+        }
+
+        let mut hash = multihash::multihash(name, data);
+        if self.mh_len < full_len {
+            let (_, digest) = split_multihash(&hash).ok_or(Error::InvalidMultihash)?;
+            let digest = digest[..self.mh_len].to_vec();
+
+            hash.clear();
+            push_varint(&mut hash, self.mh_type);
+            push_varint(&mut hash, self.mh_len as u64);
+            hash.extend_from_slice(&digest);
+        }
+
+        check_codes(self.version, self.codec, &hash)?;
+
+        Ok(CID::from_parts(self.version, self.codec, hash))
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+
+        push_varint(&mut output, self.version);
+        push_varint(&mut output, self.codec);
+        push_varint(&mut output, self.mh_type);
+        push_varint(&mut output, self.mh_len as u64);
+
+        output
+    }
+}
+
+impl TryFrom<&[u8]> for Prefix {
+    type Error = Error;
+
+    fn try_from(mut input: &[u8]) -> Result<Self, Self::Error> {
+        let mut read = || {
+            read_varint(&mut input).map_err(|e| match e.kind() {
+                io::ErrorKind::UnexpectedEof => Error::InvalidLength,
+                _ => Error::Io(e),
+            })
+        };
+
+        let version = read()?;
+        let codec = read()?;
+        let mh_type = read()?;
+        let mh_len = read()? as usize;
+
+        if !input.is_empty() {
+            return Err(Error::InvalidLength);
+        }
+        if multicodec::try_to_type(codec).is_none() {
+            return Err(Error::UnknownCodec(codec));
+        }
+
+        let prefix = Self {
+            version,
+            codec,
+            mh_type,
+            mh_len,
+        };
+        prefix.check()?;
+
+        Ok(prefix)
+    }
+}
+
+impl CID {
+    pub fn prefix(&self) -> Result<Prefix, Error> {
+        let (mh_type, digest) = split_multihash(self.hash()).ok_or(Error::InvalidMultihash)?;
+
+        Ok(Prefix {
+            version: self.version(),
+            codec: self.codec(),
+            mh_type,
+            mh_len: digest.len(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_sum() {
+        let prefix = Prefix::new("cidv1", "raw", "sha2-256").unwrap();
+        let cid = prefix.sum(b"beep boop").unwrap();
+
+        assert_eq!(
+            cid,
+            CID::new(
+                "cidv1",
+                "raw",
+                multihash::multihash("sha2-256", b"beep boop")
+            )
+            .unwrap()
+        );
+        assert_eq!(cid.prefix().unwrap(), prefix);
+
+        let v0 = Prefix::new("cidv0", "dag-pb", "sha2-256").unwrap();
+        assert_eq!(v0.sum(b"beep boop").unwrap().version(), 0);
+        assert!(matches!(
+            Prefix::new("cidv0", "raw", "sha2-256"),
+            Err(Error::InvalidV0)
+        ));
+        for (codec, mh_type) in [("nope", "sha2-256"), ("raw", "nope")] {
+            assert!(matches!(
+                Prefix::new("cidv1", codec, mh_type),
+                Err(Error::UnknownName(name)) if name == "nope"
+            ));
+        }
+
+        // the digest length of blake2b-512 is not in the low bits of its code
+        let blake2b = Prefix::new("cidv1", "raw", "blake2b-512").unwrap();
+        assert_eq!(blake2b.mh_len, 64);
+        let cid = blake2b.sum(b"beep boop").unwrap();
+        assert_eq!(cid.hash()[..4], [0xc0, 0xe4, 0x02, 0x40]);
+        assert_eq!(cid.hash()[4..], multihash::digest(0xb240, b"beep boop"));
+        assert_eq!(cid.prefix().unwrap(), blake2b);
+
+        let truncated = Prefix {
+            mh_len: 20,
+            ..prefix
+        };
+        let cid = truncated.sum(b"beep boop").unwrap();
+        assert_eq!(cid.hash()[..2], [0x12, 20]);
+        assert_eq!(cid.hash()[2..], multihash::digest(0x12, b"beep boop")[..20]);
+        assert_eq!(cid.prefix().unwrap(), truncated);
+
+        let oversized = Prefix {
+            mh_len: 33,
+            ..prefix
+        };
+        assert!(matches!(
+            oversized.sum(b"beep boop"),
+            Err(Error::DigestTooLong(33))
+        ));
+    }
+
+    #[test]
+    fn test_prefix_bytes() {
+        let prefix = Prefix::new("cidv1", "dag-cbor", "blake3").unwrap();
+        let bytes = prefix.to_bytes();
+
+        assert_eq!(bytes, [0x01, 0x71, 0x1e, 0x20]);
+        assert_eq!(Prefix::try_from(bytes.as_slice()).unwrap(), prefix);
+
+        assert!(matches!(
+            Prefix::try_from(&bytes[..3]),
+            Err(Error::InvalidLength)
+        ));
+        assert!(matches!(
+            Prefix::try_from(&[0x02, 0x71, 0x1e, 0x20][..]),
+            Err(Error::InvalidVersion(2))
+        ));
+        assert!(matches!(
+            Prefix::try_from(&[0x00, 0x71, 0x12, 0x20][..]),
+            Err(Error::InvalidV0)
+        ));
+    }
+}
@@ -0,0 +1,79 @@
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::CID;
+
+// string form for human readable formats, bytes otherwise
+impl Serialize for CID {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+}
+
+struct CidVisitor;
+
+impl de::Visitor<'_> for CidVisitor {
+    type Value = CID;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a cid")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        CID::from_str(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        CID::try_from(v).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for CID {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(CidVisitor)
+        } else {
+            deserializer.deserialize_bytes(CidVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V1: &str = "bafkreieq5jui4j25lacwomsqgjeswwl3y5zcdrresptwgmfylxo2depppq";
+    const V0: &str = "QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n";
+
+    #[test]
+    fn test_json() {
+        let cids = vec![CID::from_str(V1).unwrap(), CID::from_str(V0).unwrap()];
+
+        let json = serde_json::to_string(&cids).unwrap();
+        assert_eq!(json, format!("[\"{}\",\"{}\"]", V1, V0));
+        assert_eq!(serde_json::from_str::<Vec<CID>>(&json).unwrap(), cids);
+
+        assert!(serde_json::from_str::<CID>("\"bafy\"").is_err());
+        assert!(serde_json::from_str::<CID>("[1, 85]").is_err());
+    }
+
+    #[test]
+    fn test_binary() {
+        let cids = vec![CID::from_str(V1).unwrap(), CID::from_str(V0).unwrap()];
+
+        let bytes = bincode::serialize(&cids).unwrap();
+        assert_eq!(bincode::deserialize::<Vec<CID>>(&bytes).unwrap(), cids);
+
+        // a length followed by the binary cid
+        let bytes = bincode::serialize(&cids[0]).unwrap();
+        assert_eq!(&bytes[8..], cids[0].to_bytes());
+
+        let truncated = bincode::serialize(&cids[0].to_bytes()[..10].to_vec()).unwrap();
+        assert!(bincode::deserialize::<CID>(&truncated).is_err());
+    }
+}
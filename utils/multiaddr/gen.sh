@@ -13,24 +13,27 @@ BEGIN {
         nth = nth + 1;
 }
 END {
-    print "pub fn to_type(val: u64) -> String {"
+    print "pub fn try_to_type(val: u64) -> Option<&\047static str> {"
     print "    match val {";
     for (i = 1; i < nth; i++) {
-        print "        " name[i] " => \"" value[i] "\",";
+        if (name[i] in seen) {
+            continue;
+        }
+        seen[name[i]] = 1;
+        print "        " name[i] " => Some(\"" value[i] "\"),";
     }
-    print "        _ => unreachable!(),";
+    print "        _ => None,";
     print "    }"
-    print "    .to_string()"
     print "}"
 
     print ""
 
-    print "pub fn to_code(val: &str) -> u64 {"
+    print "pub fn try_to_code(val: &str) -> Option<u64> {"
     print "    match val {";
     for (i = 1; i < nth; i++) {
-        print "        \"" value[i] "\" => " name[i] ",";
+        print "        \"" value[i] "\" => Some(" name[i] "),";
     }
-    print "        _ => unreachable!(),";
+    print "        _ => None,";
     print "    }"
     print "}"
 }' > ./src/protocols.rs
@@ -1,7 +1,8 @@
-use std::io;
+use std::{borrow::Cow, fmt, str::FromStr};
 
-use crate::{Protocol, ProtocolIter};
+use crate::{Error, MultiAddrSlice, Protocol, ProtocolIter};
 
+#[derive(Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct MultiAddr {
     bytes: Vec<u8>,
 }
@@ -11,6 +12,11 @@ impl MultiAddr {
         Self { bytes: Vec::new() }
     }
 
+    /// Decodes a binary multiaddr, checking every protocol in it.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        MultiAddrSlice::new(bytes).map(Self::from)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.bytes.is_empty()
     }
@@ -19,31 +25,43 @@ impl MultiAddr {
         self.bytes.len()
     }
 
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         Vec::from(&self.bytes[..])
     }
 
-    pub fn push(&mut self, protocol: Protocol<'_>) {
-        let mut w = io::Cursor::<&mut Vec<u8>>::new(&mut self.bytes);
-        w.set_position(w.get_ref().len() as u64);
+    pub fn as_slice(&self) -> MultiAddrSlice<'_> {
+        MultiAddrSlice::new_unchecked(&self.bytes)
+    }
 
-        protocol.write_bytes(&mut w).expect("writing failed");
+    /// Appends `protocol`, failing with the address untouched if its value
+    /// wouldn't decode back.
+    pub fn push(&mut self, protocol: Protocol<'_>) -> Result<(), Error> {
+        protocol.check()?;
+        self.push_unchecked(protocol);
+
+        Ok(())
+    }
+
+    // for protocols known to be valid, such as the ones of another address
+    pub(crate) fn push_unchecked(&mut self, protocol: Protocol<'_>) {
+        protocol.write_bytes(&mut self.bytes);
     }
 
     pub fn pop<'s>(&mut self) -> Option<Protocol<'s>> {
         let mut slice = &self.bytes[..];
-        if slice.is_empty() {
-            return None;
-        }
 
         let mut remain = 0;
         let protocol = loop {
-            let (protocol, len) = Protocol::from_bytes(&slice).expect("from bytes failed");
-            slice = &slice[len..];
-            if slice.is_empty() {
+            let (protocol, len) = Protocol::from_bytes(slice).ok()?;
+            if len == slice.len() {
                 break protocol.acquire();
             }
 
+            slice = &slice[len..];
             remain += len;
         };
 
@@ -52,6 +70,673 @@ impl MultiAddr {
     }
 
     pub fn iter(&self) -> ProtocolIter<'_> {
-        ProtocolIter(&self.bytes)
+        self.as_slice().iter()
+    }
+
+    fn boundaries(&self) -> Vec<usize> {
+        self.as_slice().boundaries()
+    }
+
+    /// Returns this address with `other` appended.
+    pub fn encapsulate(&self, other: &MultiAddr) -> MultiAddr {
+        let mut bytes = self.bytes.clone();
+        bytes.extend_from_slice(&other.bytes);
+
+        Self { bytes }
+    }
+
+    /// Strips the last occurrence of `other` and everything after it, the
+    /// address is returned unchanged if it doesn't contain `other`.
+    pub fn decapsulate(&self, other: &MultiAddr) -> MultiAddr {
+        if other.is_empty() {
+            return self.clone();
+        }
+
+        let boundaries = self.boundaries();
+        let found = boundaries.iter().rev().find(|start| {
+            self.bytes[**start..].starts_with(&other.bytes)
+                && boundaries.contains(&(**start + other.len()))
+        });
+
+        match found {
+            Some(start) => Self {
+                bytes: self.bytes[..*start].to_vec(),
+            },
+            None => self.clone(),
+        }
+    }
+
+    pub fn starts_with(&self, other: &MultiAddr) -> bool {
+        self.as_slice().starts_with(other.as_slice())
+    }
+
+    pub fn ends_with(&self, other: &MultiAddr) -> bool {
+        self.as_slice().ends_with(other.as_slice())
+    }
+
+    /// Returns a copy of the address with the protocol at `index` swapped for
+    /// `protocol`, or `None` if there is no such protocol.
+    pub fn replace(
+        &self,
+        index: usize,
+        protocol: Protocol<'_>,
+    ) -> Result<Option<MultiAddr>, Error> {
+        protocol.check()?;
+
+        let boundaries = self.boundaries();
+        let (start, end) = match (boundaries.get(index), boundaries.get(index + 1)) {
+            (Some(start), Some(end)) => (*start, *end),
+            _ => return Ok(None),
+        };
+
+        let mut bytes = self.bytes[..start].to_vec();
+        protocol.write_bytes(&mut bytes);
+        bytes.extend_from_slice(&self.bytes[end..]);
+
+        Ok(Some(Self { bytes }))
+    }
+
+    /// Appends `/p2p/<peer_id>` unless the address already ends with it.
+    ///
+    /// Fails with the address untouched if it ends with a different peer id.
+    pub fn with_p2p(mut self, peer_id: &[u8]) -> Result<MultiAddr, MultiAddr> {
+        match self.iter().last() {
+            Some(Protocol::P2P(id)) if id == peer_id => return Ok(self),
+            Some(Protocol::P2P(_)) => return Err(self),
+            _ => {}
+        }
+
+        self.push_unchecked(Protocol::P2P(Cow::Borrowed(peer_id)));
+
+        Ok(self)
+    }
+
+    /// Splits the address before the first protocol with `code`, so the
+    /// second half starts with that protocol.
+    pub fn split_at_protocol(&self, code: u64) -> Option<(MultiAddr, MultiAddr)> {
+        let index = self.iter().position(|protocol| protocol.code() == code)?;
+        let (head, tail) = self.bytes.split_at(self.boundaries()[index]);
+
+        Some((
+            Self {
+                bytes: head.to_vec(),
+            },
+            Self {
+                bytes: tail.to_vec(),
+            },
+        ))
+    }
+}
+
+impl TryFrom<Vec<u8>> for MultiAddr {
+    type Error = Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        MultiAddrSlice::new(&bytes)?;
+
+        Ok(Self { bytes })
+    }
+}
+
+impl From<MultiAddrSlice<'_>> for MultiAddr {
+    fn from(slice: MultiAddrSlice<'_>) -> Self {
+        Self {
+            bytes: slice.as_bytes().to_vec(),
+        }
+    }
+}
+
+impl FromStr for MultiAddr {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts: Vec<&str> = s
+            .strip_prefix('/')
+            .ok_or(Error::InvalidMultiAddr)?
+            .split('/')
+            .collect();
+        if parts.last() == Some(&"") {
+            parts.pop();
+        }
+
+        let mut addr = Self::new();
+
+        let mut parts = parts.into_iter().peekable();
+        while parts.peek().is_some() {
+            addr.push_unchecked(Protocol::from_str_parts(&mut parts)?);
+        }
+
+        Ok(addr)
+    }
+}
+
+impl fmt::Debug for MultiAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MultiAddr").field(&self.to_string()).finish()
+    }
+}
+
+impl fmt::Display for MultiAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::to_code;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_string_round_trip() {
+        for s in [
+            "/ip4/1.2.3.4",
+            "/ip4/0.0.0.0",
+            "/ip6/::1",
+            "/ip6/2601:9:4f81:9700:803e:ca65:66e8:c21",
+            "/ip6/2601:9:4f81:9700:803e:ca65:66e8:c21/udp/1234/quic",
+            "/udp/0",
+            "/tcp/0",
+            "/sctp/0",
+            "/udp/65535",
+            "/tcp/65535",
+            "/dccp/1234",
+            "/memory/9",
+            "/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC",
+            "/p2p/12D3KooWRBy97UB99e3J6hiPesre1MZeuNQvfan4gBziswrRJsNK",
+            "/udp/1234/sctp/1234",
+            "/udp/1234/udt",
+            "/udp/1234/utp",
+            "/tcp/1234/http",
+            "/tcp/1234/tls/http",
+            "/tcp/1234/https",
+            "/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC/tcp/1234",
+            "/ip4/127.0.0.1/udp/1234",
+            "/ip4/127.0.0.1/udp/0",
+            "/ip4/127.0.0.1/tcp/1234",
+            "/ip4/127.0.0.1/udp/1234/quic",
+            "/ip4/127.0.0.1/udp/1234/quic-v1",
+            "/ip4/127.0.0.1/udp/1234/quic-v1/webtransport",
+            "/ip4/127.0.0.1/udp/1234/quic-v1/webtransport/certhash/uEiDDq4_xNyDorZBH3TlGazyJdOWSwvo4PUo5YHFMrvDE8g",
+            "/ip4/127.0.0.1/udp/1234/webrtc/certhash/uEiDDq4_xNyDorZBH3TlGazyJdOWSwvo4PUo5YHFMrvDE8g",
+            "/ip4/127.0.0.1/tcp/127/ws",
+            "/ip4/127.0.0.1/tcp/127/wss",
+            "/ip4/127.0.0.1/tcp/127/tls/ws",
+            "/ip4/127.0.0.1/tcp/127/noise",
+            "/dns/example.com/tcp/443/wss",
+            "/dns4/example.com",
+            "/dns6/example.com",
+            "/dnsaddr/bootstrap.libp2p.io",
+            "/unix/%2Ftmp%2Fp2p.sock",
+            "/unix/100%25",
+            "/p2p-circuit",
+            "/ip4/1.2.3.4/tcp/80/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC/p2p-circuit/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC",
+            "/ip4/127.0.0.1/tcp/9090/p2p-webrtc-star",
+            "/ip4/127.0.0.1/tcp/9090/p2p-webrtc-direct",
+            "/ip4/127.0.0.1/tcp/9090/p2p-websocket-star",
+            "/ip4/127.0.0.1/tcp/9090/ws/p2p-stardust",
+            "/ip4/127.0.0.1/udp/1234/webrtc-direct/certhash/uEiDDq4_xNyDorZBH3TlGazyJdOWSwvo4PUo5YHFMrvDE8g",
+            "/ip4/127.0.0.1/tcp/1234/plaintextv2",
+            "/ip6zone/x/ip6/fe80::1",
+            "/ip6zone/eth0/ip6/fe80::1/tcp/80",
+            "/ip4/192.168.0.0/ipcidr/16",
+            "/ip6/2001:db8::/ipcidr/32",
+            "/onion/timaq4ygg2iegci7:1234",
+            "/onion/timaq4ygg2iegci7:80/http",
+            "/onion3/vww6ybal4bd7szmgncyruucpgfkqahzddi37ktceo3ah7ngmcopnpyyd:1234",
+            "/garlic32/566niximlxdzpanmn4qouucvua3k7neniwss47li5r6ugoertzuq",
+            "/dns/example.com/tcp/443/tls/sni/example.com/http",
+            "/dns/example.com/tcp/443/https/http-path/foo%2Fbar%25",
+        ] {
+            let addr = MultiAddr::from_str(s).unwrap();
+            assert_eq!(addr.to_string(), s);
+
+            for protocol in addr.iter() {
+                assert_eq!(
+                    Protocol::from_str(&protocol.to_string()).unwrap(),
+                    protocol
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_string_to_bytes() {
+        for (s, bytes) in [
+            ("/ip4/127.0.0.1/udp/1234", "047f000001910204d2"),
+            ("/ip4/127.0.0.1/tcp/4321", "047f0000010610e1"),
+            (
+                "/ip4/127.0.0.1/udp/1234/ip4/127.0.0.1/tcp/4321",
+                "047f000001910204d2047f0000010610e1",
+            ),
+            (
+                "/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC",
+                "a503221220d52ebb89d85b02a284948203a62ff28389c57c9f42beec4ec20db76a68911c0b",
+            ),
+            ("/ip4/127.0.0.1/tcp/4321/ws", "047f0000010610e1dd03"),
+            ("/onion/aaimaq4ygg2iegci:80", "bc030010c0439831b48218480050"),
+            ("/ip4/1.2.3.4/ipcidr/24", "04010203042b18"),
+            ("/ip6zone/x", "2a0178"),
+            ("/webrtc-direct/webrtc", "98029902"),
+            ("/http-path/a%2Fb", "e10303612f62"),
+        ] {
+            assert_eq!(hex(&MultiAddr::from_str(s).unwrap().to_bytes()), bytes);
+        }
+    }
+
+    #[test]
+    fn test_string_aliases() {
+        let addr = MultiAddr::from_str("/ip4/127.0.0.1/tcp/1234/").unwrap();
+        assert_eq!(addr.to_string(), "/ip4/127.0.0.1/tcp/1234");
+
+        let addr =
+            MultiAddr::from_str("/ipfs/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC").unwrap();
+        assert_eq!(
+            addr.to_string(),
+            "/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC"
+        );
+
+        let addr =
+            MultiAddr::from_str("/p2p/bafzbeigvf25ytwc3akrijfecaotc74udrhcxzh2cx3we5qqnw5vgrei4bm")
+                .unwrap();
+        assert_eq!(
+            addr.to_string(),
+            "/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC"
+        );
+
+        assert!(MultiAddr::from_str("/").unwrap().is_empty());
+        assert_eq!(
+            Protocol::from_str(
+                "/certhash/f1220c3ab8ff13720e8ad9047dd39466b3c8974e592c2fa383d4a3960714caef0c4f2"
+            )
+            .unwrap()
+            .to_string(),
+            "/certhash/uEiDDq4_xNyDorZBH3TlGazyJdOWSwvo4PUo5YHFMrvDE8g"
+        );
+    }
+
+    #[test]
+    fn test_string_invalid() {
+        for s in [
+            "",
+            "ip4/1.2.3.4",
+            "/ip4",
+            "/ip4/::1",
+            "/ip4/1.2.3.4/tcp",
+            "/ip4/1.2.3.4/tcp/65536",
+            "/ip6/1.2.3.4",
+            "/udp/-1",
+            "/sctp/abc",
+            "/p2p/Qm0OIl",
+            "/p2p/bafkreieq5jui4j25lacwomsqgjeswwl3y5zcdrresptwgmfylxo2depppq",
+            "/certhash/!abc",
+            "/unix/%zz",
+            "/unknown/1234",
+            "//ip4/1.2.3.4",
+            "/ip6zone//ip6/fe80::1",
+            "/ipcidr/256",
+            "/ipcidr/129",
+            "/onion/timaq4ygg2iegci7",
+            "/onion/timaq4ygg2iegci7:0",
+            "/onion/timaq4ygg2iegci7:65536",
+            "/onion/timaq4ygg2iegci:80",
+            "/onion3/vww6ybal4bd7szmgncyruucpgfkqahzddi37ktceo3ah7ngmcopnpyy:1234",
+            "/garlic32/566niximlxdzpanmn4qouucvua3k7neniwss47li5r6ugoertz",
+            "/garlic64/AAAA",
+        ] {
+            assert!(MultiAddr::from_str(s).is_err(), "{}", s);
+        }
+
+        assert!(Protocol::from_str("/tcp/80/udp/80").is_err());
+
+        assert_eq!(
+            MultiAddr::from_str("/ip4").err(),
+            Some(Error::MissingValue("ip4"))
+        );
+        assert_eq!(
+            MultiAddr::from_str("/ip4/1.2.3.4/tcp/65536").err(),
+            Some(Error::InvalidValue("tcp"))
+        );
+        assert_eq!(
+            MultiAddr::from_str("/unknown/1234").err(),
+            Some(Error::UnknownProtocolName("unknown".to_string()))
+        );
+        assert_eq!(
+            MultiAddr::from_str("ip4/1.2.3.4").err(),
+            Some(Error::InvalidMultiAddr)
+        );
+    }
+
+    #[test]
+    fn test_garlic64() {
+        let bytes: Vec<u8> = (0..=255).cycle().take(400).collect();
+
+        let s = Protocol::Garlic64(Cow::Borrowed(&bytes)).to_string();
+        assert!(!s["/garlic64/".len()..].contains(['+', '/']));
+        assert_eq!(
+            Protocol::from_str(&s).unwrap(),
+            Protocol::Garlic64(Cow::Owned(bytes))
+        );
+
+        let addr = MultiAddr::from_str(&format!("{}/tcp/1234", s)).unwrap();
+        assert_eq!(
+            MultiAddr::from_bytes(&addr.to_bytes()).unwrap().to_string(),
+            addr.to_string()
+        );
+    }
+
+    fn addr(s: &str) -> MultiAddr {
+        MultiAddr::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_encapsulate() {
+        let base = addr("/ip4/127.0.0.1/tcp/4001");
+        let peer = addr("/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC");
+
+        let full = base.encapsulate(&peer);
+        assert_eq!(
+            full,
+            addr("/ip4/127.0.0.1/tcp/4001/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC")
+        );
+        assert_eq!(full.decapsulate(&peer), base);
+        assert_eq!(full.decapsulate(&addr("/tcp/4001")), addr("/ip4/127.0.0.1"));
+        assert_eq!(full.decapsulate(&addr("/udp/4001")), full);
+        assert_eq!(full.decapsulate(&MultiAddr::new()), full);
+
+        // the last occurrence is removed
+        let relayed = addr("/ip4/1.2.3.4/tcp/1/p2p-circuit/ip4/5.6.7.8/tcp/1");
+        assert_eq!(
+            relayed.decapsulate(&addr("/tcp/1")),
+            addr("/ip4/1.2.3.4/tcp/1/p2p-circuit/ip4/5.6.7.8")
+        );
+    }
+
+    #[test]
+    fn test_prefix_and_suffix() {
+        let full = addr("/ip4/127.0.0.1/tcp/4321/ws");
+
+        assert!(full.starts_with(&addr("/ip4/127.0.0.1")));
+        assert!(full.starts_with(&MultiAddr::new()));
+        assert!(!full.starts_with(&addr("/ip4/127.0.0.2")));
+        assert!(full.ends_with(&addr("/tcp/4321/ws")));
+        assert!(full.ends_with(&full));
+        assert!(!full.ends_with(&addr("/tcp/4321")));
+
+        // the bytes of /tcp/4321 appear at the end of the peer id
+        let mut tricky = MultiAddr::new();
+        tricky
+            .push(Protocol::P2P(Cow::Borrowed(&[
+                0x01, 0x02, 0x06, 0x10, 0xe1,
+            ])))
+            .unwrap();
+        let tcp = addr("/tcp/4321");
+        assert!(tricky.to_bytes().ends_with(&tcp.to_bytes()));
+        assert!(!tricky.ends_with(&tcp));
+        assert_eq!(tricky.decapsulate(&tcp), tricky);
+    }
+
+    #[test]
+    fn test_replace() {
+        let full = addr("/ip4/127.0.0.1/tcp/4321/ws");
+
+        assert_eq!(
+            full.replace(1, Protocol::UDP(1234)),
+            Ok(Some(addr("/ip4/127.0.0.1/udp/1234/ws")))
+        );
+        assert_eq!(
+            full.replace(2, Protocol::WSS),
+            Ok(Some(addr("/ip4/127.0.0.1/tcp/4321/wss")))
+        );
+        assert_eq!(full.replace(3, Protocol::WSS), Ok(None));
+        assert_eq!(
+            full.replace(0, Protocol::IPCidr(200)),
+            Err(Error::InvalidValue("ipcidr"))
+        );
+    }
+
+    #[test]
+    fn test_push_invalid() {
+        for (protocol, err) in [
+            (Protocol::IPCidr(200), Error::InvalidValue("ipcidr")),
+            (
+                Protocol::Garlic32(Cow::Borrowed(&[1, 2, 3])),
+                Error::InvalidValue("garlic32"),
+            ),
+            (
+                Protocol::Garlic64(Cow::Borrowed(&[0; 10])),
+                Error::InvalidValue("garlic64"),
+            ),
+            (
+                Protocol::IP6Zone(Cow::Borrowed("")),
+                Error::InvalidValue("ip6zone"),
+            ),
+            (
+                Protocol::IP6Zone(Cow::Borrowed("eth/0")),
+                Error::InvalidValue("ip6zone"),
+            ),
+            (Protocol::Onion([1; 10], 0), Error::InvalidValue("onion")),
+            (Protocol::Onion3([1; 35], 0), Error::InvalidValue("onion3")),
+            (
+                Protocol::DNS(Cow::Borrowed("a/b")),
+                Error::InvalidValue("dns"),
+            ),
+            (
+                Protocol::DNS4(Cow::Borrowed("a/b")),
+                Error::InvalidValue("dns4"),
+            ),
+            (
+                Protocol::DNS6(Cow::Borrowed("a/b")),
+                Error::InvalidValue("dns6"),
+            ),
+            (
+                Protocol::DNSAddr(Cow::Borrowed("a/b")),
+                Error::InvalidValue("dnsaddr"),
+            ),
+            (
+                Protocol::SNI(Cow::Borrowed("a/b")),
+                Error::InvalidValue("sni"),
+            ),
+        ] {
+            let mut base = addr("/ip4/127.0.0.1");
+            assert_eq!(base.push(protocol), Err(err));
+            assert_eq!(base, addr("/ip4/127.0.0.1"));
+        }
+
+        let mut addr = MultiAddr::new();
+        addr.push(Protocol::IPCidr(128)).unwrap();
+        addr.push(Protocol::Onion([1; 10], 80)).unwrap();
+        addr.push(Protocol::Garlic32(Cow::Borrowed(&[1; 32])))
+            .unwrap();
+        addr.push(Protocol::IP6Zone(Cow::Borrowed("eth0"))).unwrap();
+        addr.push(Protocol::DNS(Cow::Borrowed("example.com")))
+            .unwrap();
+        assert_eq!(MultiAddr::from_str(&addr.to_string()), Ok(addr));
+    }
+
+    #[test]
+    fn test_with_p2p() {
+        let peer = addr("/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC");
+        let id = match peer.iter().next() {
+            Some(Protocol::P2P(id)) => id,
+            _ => unreachable!(),
+        };
+
+        let base = addr("/ip4/127.0.0.1/tcp/4001");
+        let full = base.clone().with_p2p(&id).unwrap();
+        assert_eq!(full, base.encapsulate(&peer));
+        assert_eq!(full.clone().with_p2p(&id), Ok(full.clone()));
+        assert_eq!(full.clone().with_p2p(&[0x00, 0x01, 0xff]), Err(full));
+    }
+
+    #[test]
+    fn test_split_at_protocol() {
+        let relayed = addr(
+            "/ip4/1.2.3.4/tcp/1/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC/p2p-circuit/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC",
+        );
+
+        let (relay, rest) = relayed.split_at_protocol(to_code("p2p-circuit")).unwrap();
+        assert_eq!(
+            relay,
+            addr("/ip4/1.2.3.4/tcp/1/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC")
+        );
+        assert_eq!(
+            rest,
+            addr("/p2p-circuit/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC")
+        );
+        assert_eq!(relay.encapsulate(&rest), relayed);
+
+        let (head, tail) = relayed.split_at_protocol(to_code("ip4")).unwrap();
+        assert!(head.is_empty());
+        assert_eq!(tail, relayed);
+
+        assert_eq!(relayed.split_at_protocol(to_code("udp")), None);
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut addrs = vec![
+            addr("/ip4/127.0.0.1/tcp/2"),
+            addr("/ip4/127.0.0.1/tcp/1"),
+            addr("/ip4/127.0.0.1/tcp/1"),
+        ];
+        addrs.sort();
+        addrs.dedup();
+
+        assert_eq!(
+            addrs,
+            [addr("/ip4/127.0.0.1/tcp/1"), addr("/ip4/127.0.0.1/tcp/2")]
+        );
+        assert_eq!(
+            format!("{:?}", addrs[0]),
+            "MultiAddr(\"/ip4/127.0.0.1/tcp/1\")"
+        );
+        assert_eq!(Protocol::TCP(1).code(), 6);
+        assert_eq!(Protocol::P2PCircuit.name(), "p2p-circuit");
+    }
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        for s in [
+            "/ip4/127.0.0.1/udp/1234/quic-v1/webtransport/certhash/uEiDDq4_xNyDorZBH3TlGazyJdOWSwvo4PUo5YHFMrvDE8g",
+            "/ip6/::1/tcp/443/wss/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC",
+            "/dns4/example.com/tcp/443/tls/http",
+            "/unix/%2Ftmp%2Fp2p.sock",
+            "/memory/9",
+            "/onion/timaq4ygg2iegci7:80/http",
+            "/onion3/vww6ybal4bd7szmgncyruucpgfkqahzddi37ktceo3ah7ngmcopnpyyd:1234",
+            "/ip6/2001:db8::/ipcidr/128",
+        ] {
+            let addr = MultiAddr::from_str(s).unwrap();
+            let bytes = addr.to_bytes();
+
+            let decoded = MultiAddr::try_from(bytes.clone()).unwrap();
+            assert_eq!(decoded.to_string(), s);
+            assert_eq!(MultiAddr::from_bytes(&bytes).unwrap().to_string(), s);
+
+            // every cut that doesn't fall between two protocols is rejected
+            let mut ends = vec![0];
+            let mut end = 0;
+            for protocol in addr.iter() {
+                let mut one = MultiAddr::new();
+                one.push(protocol).unwrap();
+                end += one.len();
+                ends.push(end);
+            }
+            for cut in 0..bytes.len() {
+                assert_eq!(
+                    MultiAddr::from_bytes(&bytes[..cut]).is_ok(),
+                    ends.contains(&cut),
+                    "{} cut at {}",
+                    s,
+                    cut
+                );
+            }
+        }
+
+        let mut addr = MultiAddr::from_str("/ip4/127.0.0.1/tcp/4321/ws").unwrap();
+        assert_eq!(addr.pop(), Some(Protocol::WS));
+        assert_eq!(addr.pop(), Some(Protocol::TCP(4321)));
+        assert_eq!(addr.to_string(), "/ip4/127.0.0.1");
+        assert_eq!(addr.pop(), Some(Protocol::IPv4([127, 0, 0, 1].into())));
+        assert_eq!(addr.pop(), None);
+    }
+
+    #[test]
+    fn test_from_bytes_invalid() {
+        for (bytes, err) in [
+            ("04", Error::UnexpectedEnd),
+            ("047f0000", Error::UnexpectedEnd),
+            ("0610", Error::UnexpectedEnd),
+            ("80", Error::UnexpectedEnd),
+            ("ffffffffffffffffffff01", Error::InvalidVarint),
+            ("ff01", Error::UnknownProtocol(0xff)),
+            ("2a00", Error::InvalidValue("ip6zone")),
+            ("bf0303aabbcc", Error::InvalidValue("garlic32")),
+            ("bc030010c0439831b482184800", Error::UnexpectedEnd),
+            ("a503", Error::UnexpectedEnd),
+            ("a50322", Error::UnexpectedEnd),
+            ("a503ffffffffffffffffff01", Error::UnexpectedEnd),
+            ("900302ff00", Error::InvalidUtf8),
+            ("047f0000010610e1ff", Error::UnexpectedEnd),
+            ("bc030010c0439831b48218480000", Error::InvalidValue("onion")),
+            (
+                "bd03adadec040be047f9658668b11a504f3155001f231a37f54c4476c07fb4cc139ed7e3030000",
+                Error::InvalidValue("onion3"),
+            ),
+            ("2b81", Error::InvalidValue("ipcidr")),
+            ("3503612f62", Error::InvalidValue("dns")),
+            ("3803612f62", Error::InvalidValue("dnsaddr")),
+            ("c10303612f62", Error::InvalidValue("sni")),
+        ] {
+            assert_eq!(
+                MultiAddr::try_from(unhex(bytes)).err(),
+                Some(err),
+                "{}",
+                bytes
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_garbage() {
+        // no input may panic, whatever it decodes to
+        let mut seed = 0x2545f4914f6cdd1du64;
+        for _ in 0..10000 {
+            let mut bytes = Vec::new();
+            for _ in 0..(seed % 48) {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+
+                // bias towards known protocol codes
+                bytes.push(match seed % 4 {
+                    0 => [0x04, 0x06, 0x29, 0x35, 0x90, 0xa5, 0xd2][(seed >> 8) as usize % 7],
+                    _ => (seed >> 8) as u8,
+                });
+            }
+
+            if let Ok(mut addr) = MultiAddr::try_from(bytes.clone()) {
+                assert_eq!(addr.to_bytes(), bytes);
+                let _ = addr.to_string();
+
+                let count = addr.iter().count();
+                for _ in 0..count {
+                    assert!(addr.pop().is_some());
+                }
+                assert!(addr.is_empty());
+            }
+        }
     }
 }
@@ -0,0 +1,405 @@
+use std::{
+    io::{self, Read, Write},
+    net::{SocketAddr, TcpStream, UdpSocket},
+    time::{Duration, Instant},
+};
+
+const TYPE_TXT: u16 = 16;
+const TYPE_OPT: u16 = 41;
+const CLASS_IN: u16 = 1;
+
+const RCODE_NXDOMAIN: u16 = 3;
+
+const UDP_PAYLOAD: u16 = 4096;
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn read_u16(msg: &[u8], pos: usize) -> io::Result<u16> {
+    match msg.get(pos..pos + 2) {
+        Some(b) => Ok(u16::from_be_bytes([b[0], b[1]])),
+        None => Err(invalid("truncated dns message")),
+    }
+}
+
+pub(crate) fn build_query(id: u16, name: &str, qtype: u16) -> io::Result<Vec<u8>> {
+    let mut query = Vec::with_capacity(name.len() + 30);
+
+    query.extend_from_slice(&id.to_be_bytes());
+    // recursion desired, one question and the edns0 record
+    query.extend_from_slice(&[0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 1]);
+
+    for label in name.trim_end_matches('.').split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err(invalid("invalid domain name"));
+        }
+
+        query.push(label.len() as u8);
+        query.extend_from_slice(label.as_bytes());
+    }
+    query.push(0);
+    query.extend_from_slice(&qtype.to_be_bytes());
+    query.extend_from_slice(&CLASS_IN.to_be_bytes());
+
+    // edns0, lets the server answer with more than 512 bytes over udp
+    query.push(0);
+    query.extend_from_slice(&TYPE_OPT.to_be_bytes());
+    query.extend_from_slice(&UDP_PAYLOAD.to_be_bytes());
+    query.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+
+    Ok(query)
+}
+
+// returns the position right after the name starting at `pos`
+fn skip_name(msg: &[u8], mut pos: usize) -> io::Result<usize> {
+    loop {
+        let len = *msg.get(pos).ok_or_else(|| invalid("truncated dns name"))?;
+        match len {
+            0 => return Ok(pos + 1),
+            // a compression pointer always ends the name
+            _ if len & 0xc0 == 0xc0 => return Ok(pos + 2),
+            _ => pos += 1 + len as usize,
+        }
+    }
+}
+
+pub(crate) struct Response {
+    pub(crate) truncated: bool,
+    pub(crate) records: Vec<Vec<u8>>,
+}
+
+// the end of the question section of a message with a single question
+fn question_end(msg: &[u8]) -> io::Result<usize> {
+    if read_u16(msg, 4)? != 1 {
+        return Err(invalid("expected a single dns question"));
+    }
+
+    let end = skip_name(msg, 12)? + 4;
+    match end <= msg.len() {
+        true => Ok(end),
+        false => Err(invalid("truncated dns question")),
+    }
+}
+
+/// Whether `msg` is a response to `query`, with the same id and question.
+///
+/// Names are compared ignoring case since some servers don't echo it.
+pub(crate) fn is_response_to(query: &[u8], msg: &[u8]) -> bool {
+    let matches = || -> io::Result<bool> {
+        let end = question_end(query)?;
+
+        Ok(read_u16(msg, 0)? == read_u16(query, 0)?
+            && read_u16(msg, 2)? & 0x8000 != 0
+            && question_end(msg)? == end
+            && msg[12..end].eq_ignore_ascii_case(&query[12..end]))
+    };
+
+    matches().unwrap_or(false)
+}
+
+/// Extracts the data of every answer of type `qtype` from a response.
+pub(crate) fn parse_response(id: u16, qtype: u16, msg: &[u8]) -> io::Result<Response> {
+    if read_u16(msg, 0)? != id {
+        return Err(invalid("dns response id mismatch"));
+    }
+
+    let flags = read_u16(msg, 2)?;
+    if flags & 0x8000 == 0 {
+        return Err(invalid("dns message is not a response"));
+    }
+
+    let mut response = Response {
+        truncated: flags & 0x0200 != 0,
+        records: Vec::new(),
+    };
+    match flags & 0x000f {
+        0 if !response.truncated => {}
+        0 | RCODE_NXDOMAIN => return Ok(response),
+        rcode => {
+            return Err(io::Error::other(format!(
+                "dns server failed with rcode {}",
+                rcode
+            )))
+        }
+    }
+
+    let questions = read_u16(msg, 4)?;
+    let answers = read_u16(msg, 6)?;
+
+    let mut pos = 12;
+    for _ in 0..questions {
+        pos = skip_name(msg, pos)? + 4;
+    }
+
+    for _ in 0..answers {
+        pos = skip_name(msg, pos)?;
+
+        let rtype = read_u16(msg, pos)?;
+        let len = read_u16(msg, pos + 8)? as usize;
+        let data = msg
+            .get(pos + 10..pos + 10 + len)
+            .ok_or_else(|| invalid("truncated dns record"))?;
+        if rtype == qtype {
+            response.records.push(data.to_vec());
+        }
+
+        pos += 10 + len;
+    }
+
+    Ok(response)
+}
+
+// a txt record is a sequence of length prefixed strings, read as one
+pub(crate) fn txt_string(data: &[u8]) -> io::Result<String> {
+    let mut output = Vec::with_capacity(data.len());
+
+    let mut pos = 0;
+    while pos < data.len() {
+        let len = data[pos] as usize;
+        let part = data
+            .get(pos + 1..pos + 1 + len)
+            .ok_or_else(|| invalid("truncated txt record"))?;
+
+        output.extend_from_slice(part);
+        pos += 1 + len;
+    }
+
+    Ok(String::from_utf8_lossy(&output).into_owned())
+}
+
+// an unpredictable id from the os random source
+fn query_id() -> io::Result<u16> {
+    let mut id = [0u8; 2];
+    getrandom::getrandom(&mut id).map_err(io::Error::from)?;
+
+    Ok(u16::from_be_bytes(id))
+}
+
+fn exchange_udp(nameserver: SocketAddr, query: &[u8]) -> io::Result<Vec<u8>> {
+    let local: SocketAddr = match nameserver {
+        SocketAddr::V4(_) => ([0, 0, 0, 0], 0).into(),
+        SocketAddr::V6(_) => ([0u16; 8], 0).into(),
+    };
+
+    let socket = UdpSocket::bind(local)?;
+    socket.connect(nameserver)?;
+    socket.send(query)?;
+
+    // packets that don't answer the query are dropped rather than failing
+    // it, so a spoofed or stale packet can't cut a lookup short
+    let deadline = Instant::now() + TIMEOUT;
+    let mut buf = vec![0u8; UDP_PAYLOAD as usize];
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+        socket.set_read_timeout(Some(remaining))?;
+
+        let len = socket.recv(&mut buf)?;
+        if is_response_to(query, &buf[..len]) {
+            buf.truncate(len);
+            return Ok(buf);
+        }
+    }
+}
+
+fn exchange_tcp(nameserver: SocketAddr, query: &[u8]) -> io::Result<Vec<u8>> {
+    let mut stream = TcpStream::connect_timeout(&nameserver, TIMEOUT)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    stream.write_all(&(query.len() as u16).to_be_bytes())?;
+    stream.write_all(query)?;
+
+    let mut len = [0u8; 2];
+    stream.read_exact(&mut len)?;
+    let mut buf = vec![0u8; u16::from_be_bytes(len) as usize];
+    stream.read_exact(&mut buf)?;
+
+    match is_response_to(query, &buf) {
+        true => Ok(buf),
+        false => Err(invalid("dns response doesn't match the query")),
+    }
+}
+
+/// Queries `nameserver` for the txt records of `name`, over tcp when the udp
+/// answer doesn't fit.
+pub(crate) fn query_txt(nameserver: SocketAddr, name: &str) -> io::Result<Vec<String>> {
+    let id = query_id()?;
+    let query = build_query(id, name, TYPE_TXT)?;
+
+    let mut response = parse_response(id, TYPE_TXT, &exchange_udp(nameserver, &query)?)?;
+    if response.truncated {
+        response = parse_response(id, TYPE_TXT, &exchange_tcp(nameserver, &query)?)?;
+    }
+
+    response
+        .records
+        .iter()
+        .map(|data| txt_string(data))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_build_query() {
+        let query = build_query(0x1234, "_dnsaddr.example.com.", TYPE_TXT).unwrap();
+
+        let mut expected = vec![0x12, 0x34, 0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 1];
+        expected.extend_from_slice(b"\x08_dnsaddr\x07example\x03com\x00");
+        expected.extend_from_slice(&[0, 16, 0, 1]);
+        expected.extend_from_slice(&[0, 0, 41, 0x10, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(query, expected);
+
+        assert!(build_query(1, "a..com", TYPE_TXT).is_err());
+        assert!(build_query(1, &"a".repeat(64), TYPE_TXT).is_err());
+    }
+
+    fn message(flags: u16, answers: &[(u16, &[u8])]) -> Vec<u8> {
+        let mut msg = vec![0x12, 0x34];
+        msg.extend_from_slice(&flags.to_be_bytes());
+        msg.extend_from_slice(&[0, 1]);
+        msg.extend_from_slice(&(answers.len() as u16).to_be_bytes());
+        msg.extend_from_slice(&[0, 0, 0, 0]);
+        msg.extend_from_slice(b"\x07example\x03com\x00\x00\x10\x00\x01");
+
+        for (rtype, data) in answers {
+            // points back to the name of the question
+            msg.extend_from_slice(&[0xc0, 12]);
+            msg.extend_from_slice(&rtype.to_be_bytes());
+            msg.extend_from_slice(&[0, 1, 0, 0, 0x0e, 0x10]);
+            msg.extend_from_slice(&(data.len() as u16).to_be_bytes());
+            msg.extend_from_slice(data);
+        }
+
+        msg
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let msg = message(
+            0x8180,
+            &[
+                (TYPE_TXT, b"\x0cdnsaddr=/ip4"),
+                (5, b"\x03cname\x00"),
+                (TYPE_TXT, b"\x05hello\x06 world"),
+            ],
+        );
+
+        let response = parse_response(0x1234, TYPE_TXT, &msg).unwrap();
+        assert!(!response.truncated);
+        assert_eq!(response.records.len(), 2);
+        assert_eq!(txt_string(&response.records[0]).unwrap(), "dnsaddr=/ip4");
+        assert_eq!(txt_string(&response.records[1]).unwrap(), "hello world");
+
+        let nxdomain = parse_response(0x1234, TYPE_TXT, &message(0x8183, &[])).unwrap();
+        assert!(nxdomain.records.is_empty());
+
+        let truncated = parse_response(0x1234, TYPE_TXT, &message(0x8380, &[])).unwrap();
+        assert!(truncated.truncated);
+
+        assert!(parse_response(0x1234, TYPE_TXT, &message(0x8182, &[])).is_err());
+        assert!(parse_response(0x4321, TYPE_TXT, &msg).is_err());
+        assert!(parse_response(0x1234, TYPE_TXT, &message(0x0100, &[])).is_err());
+
+        // cutting the message anywhere fails without panicking
+        for cut in 0..msg.len() {
+            assert!(parse_response(0x1234, TYPE_TXT, &msg[..cut]).is_err());
+        }
+        assert!(txt_string(b"\x05abc").is_err());
+    }
+
+    #[test]
+    fn test_is_response_to() {
+        let query = build_query(0x1234, "example.com", TYPE_TXT).unwrap();
+        let msg = message(0x8180, &[(TYPE_TXT, b"\x02hi")]);
+        assert!(is_response_to(&query, &msg));
+
+        let upper = build_query(0x1234, "EXAMPLE.com", TYPE_TXT).unwrap();
+        assert!(is_response_to(&upper, &msg));
+
+        let other_id = build_query(0x4321, "example.com", TYPE_TXT).unwrap();
+        assert!(!is_response_to(&other_id, &msg));
+        let other_name = build_query(0x1234, "example.org", TYPE_TXT).unwrap();
+        assert!(!is_response_to(&other_name, &msg));
+        let other_type = build_query(0x1234, "example.com", 1).unwrap();
+        assert!(!is_response_to(&other_type, &msg));
+
+        // the query itself is not a response
+        assert!(!is_response_to(&query, &query));
+        for cut in 0..20 {
+            assert!(!is_response_to(&query, &msg[..cut]));
+        }
+    }
+
+    #[test]
+    fn test_exchange_udp_skips_stray_packets() {
+        let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let nameserver = server.local_addr().unwrap();
+
+        let handle = std::thread::spawn(move || {
+            let mut buf = [0u8; 512];
+            let (_, peer) = server.recv_from(&mut buf).unwrap();
+
+            let mut stray = message(0x8180, &[]);
+            stray[0] ^= 0xff;
+            server.send_to(&stray, peer).unwrap();
+            server.send_to(b"junk", peer).unwrap();
+            server
+                .send_to(&message(0x8180, &[(TYPE_TXT, b"\x02ok")]), peer)
+                .unwrap();
+        });
+
+        let query = build_query(0x1234, "example.com", TYPE_TXT).unwrap();
+        let msg = exchange_udp(nameserver, &query).unwrap();
+        let response = parse_response(0x1234, TYPE_TXT, &msg).unwrap();
+        assert_eq!(txt_string(&response.records[0]).unwrap(), "ok");
+
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_exchange_tcp_checks_question() {
+        let server = TcpListener::bind("127.0.0.1:0").unwrap();
+        let nameserver = server.local_addr().unwrap();
+
+        let handle = std::thread::spawn(move || {
+            for _ in 0..2 {
+                let (mut stream, _) = server.accept().unwrap();
+                let mut len = [0u8; 2];
+                stream.read_exact(&mut len).unwrap();
+                let mut query = vec![0u8; u16::from_be_bytes(len) as usize];
+                stream.read_exact(&mut query).unwrap();
+
+                let msg = message(0x8180, &[(TYPE_TXT, b"\x02ok")]);
+                stream.write_all(&(msg.len() as u16).to_be_bytes()).unwrap();
+                stream.write_all(&msg).unwrap();
+            }
+        });
+
+        let query = build_query(0x1234, "example.com", TYPE_TXT).unwrap();
+        let msg = exchange_tcp(nameserver, &query).unwrap();
+        let response = parse_response(0x1234, TYPE_TXT, &msg).unwrap();
+        assert_eq!(txt_string(&response.records[0]).unwrap(), "ok");
+
+        // same id, other name
+        let query = build_query(0x1234, "example.org", TYPE_TXT).unwrap();
+        let err = exchange_tcp(nameserver, &query).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_query_id() {
+        let ids: Vec<u16> = (0..8).map(|_| query_id().unwrap()).collect();
+        assert!(ids.iter().any(|id| *id != ids[0]));
+    }
+}
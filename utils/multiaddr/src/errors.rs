@@ -1,2 +1,79 @@
+use std::{error, fmt, io};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    UnknownProtocol(u64),
+    UnknownProtocolName(String),
+    InvalidVarint,
+    UnexpectedEnd,
+    InvalidUtf8,
+    MissingValue(&'static str),
+    InvalidValue(&'static str),
+    InvalidMultiAddr,
+    InvalidCidr,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownProtocol(v) => write!(f, "unknown protocol 0x{:x}", v),
+            Self::UnknownProtocolName(v) => write!(f, "unknown protocol {}", v),
+            Self::InvalidVarint => write!(f, "invalid varint"),
+            Self::UnexpectedEnd => write!(f, "multiaddr ends in the middle of a protocol"),
+            Self::InvalidUtf8 => write!(f, "invalid utf-8 in protocol value"),
+            Self::MissingValue(v) => write!(f, "missing value for protocol {}", v),
+            Self::InvalidValue(v) => write!(f, "invalid value for protocol {}", v),
+            Self::InvalidMultiAddr => write!(f, "malformed multiaddr string"),
+            Self::InvalidCidr => write!(f, "expected an /ip4 or /ip6 address with /ipcidr"),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
 #[derive(Debug)]
-pub struct UnknownProtocolError;
+pub enum ResolveError {
+    Io(io::Error),
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl error::Error for ResolveError {}
+
+impl From<io::Error> for ResolveError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UrlError {
+    /// The address has no url form, holds the first protocol that doesn't
+    /// fit.
+    Unsupported(&'static str),
+    /// The address stops before the http or websocket protocol.
+    Incomplete,
+    UnsupportedScheme(String),
+    /// The url is malformed or has a part without a multiaddr form, like a
+    /// query string.
+    InvalidUrl(&'static str),
+}
+
+impl fmt::Display for UrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported(v) => write!(f, "protocol {} has no url equivalent", v),
+            Self::Incomplete => write!(f, "multiaddr is not an http or websocket address"),
+            Self::UnsupportedScheme(v) => write!(f, "unsupported url scheme {}", v),
+            Self::InvalidUrl(v) => write!(f, "invalid or unsupported url {}", v),
+        }
+    }
+}
+
+impl error::Error for UrlError {}
@@ -0,0 +1,238 @@
+use std::net::IpAddr;
+
+use crate::{Error, MultiAddr, Protocol};
+
+/// What a filter does with the addresses matching a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Allow,
+    Deny,
+}
+
+// a network with the host bits cleared, ipv4 kept as ipv4
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct IpNet {
+    ip: IpAddr,
+    prefix: u8,
+}
+
+fn masked(ip: IpAddr, prefix: u8) -> IpAddr {
+    match ip {
+        IpAddr::V4(ip) => {
+            let mask = u32::MAX.checked_shl(32 - prefix as u32).unwrap_or(0);
+            IpAddr::V4((u32::from(ip) & mask).into())
+        }
+        IpAddr::V6(ip) => {
+            let mask = u128::MAX.checked_shl(128 - prefix as u32).unwrap_or(0);
+            IpAddr::V6((u128::from(ip) & mask).into())
+        }
+    }
+}
+
+fn canonical(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(ip, IpAddr::V4),
+        ip => ip,
+    }
+}
+
+impl IpNet {
+    fn parse(rule: &MultiAddr) -> Result<Self, Error> {
+        let mut iter = rule.iter();
+
+        let (ip, max) = match iter.next() {
+            Some(Protocol::IPv4(ip)) => (IpAddr::V4(ip), 32),
+            Some(Protocol::IPv6(ip)) => (IpAddr::V6(ip), 128),
+            _ => return Err(Error::InvalidCidr),
+        };
+        let prefix = match iter.next() {
+            Some(Protocol::IPCidr(prefix)) if prefix <= max => prefix,
+            Some(Protocol::IPCidr(_)) => return Err(Error::InvalidValue("ipcidr")),
+            // a bare address is a single host
+            None => max,
+            _ => return Err(Error::InvalidCidr),
+        };
+        if iter.next().is_some() {
+            return Err(Error::InvalidCidr);
+        }
+
+        Ok(Self {
+            ip: masked(ip, prefix),
+            prefix,
+        })
+    }
+
+    fn contains(&self, ip: IpAddr) -> bool {
+        ip.is_ipv4() == self.ip.is_ipv4() && masked(ip, self.prefix) == self.ip
+    }
+}
+
+/// Allow and deny rules on ip networks, like `Swarm.AddrFilters` of kubo.
+///
+/// Rules are written `/ip4/10.0.0.0/ipcidr/8`, a bare `/ip4/10.1.2.3` is a
+/// single host. The most specific rule containing the ip of an address
+/// decides, and addresses no rule matches get `default_action`.
+#[derive(Debug, Clone)]
+pub struct MultiAddrFilter {
+    rules: Vec<(IpNet, Action)>,
+    pub default_action: Action,
+}
+
+impl Default for MultiAddrFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MultiAddrFilter {
+    /// An empty filter allowing everything.
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            default_action: Action::Allow,
+        }
+    }
+
+    /// Adds a rule, replacing the action of an existing rule on the same
+    /// network.
+    pub fn add(&mut self, rule: &MultiAddr, action: Action) -> Result<(), Error> {
+        let net = IpNet::parse(rule)?;
+
+        match self.rules.iter_mut().find(|(other, _)| *other == net) {
+            Some(existing) => existing.1 = action,
+            None => self.rules.push((net, action)),
+        }
+
+        Ok(())
+    }
+
+    pub fn allow(&mut self, rule: &MultiAddr) -> Result<(), Error> {
+        self.add(rule, Action::Allow)
+    }
+
+    pub fn deny(&mut self, rule: &MultiAddr) -> Result<(), Error> {
+        self.add(rule, Action::Deny)
+    }
+
+    /// Removes the rule on the network of `rule`, returning its action.
+    pub fn remove(&mut self, rule: &MultiAddr) -> Result<Option<Action>, Error> {
+        let net = IpNet::parse(rule)?;
+        let index = self.rules.iter().position(|(other, _)| *other == net);
+
+        Ok(index.map(|index| self.rules.remove(index).1))
+    }
+
+    /// The action for `addr`, decided by the ip it starts with.
+    ///
+    /// Ipv4 mapped ipv6 addresses are checked against the ipv4 rules, and
+    /// addresses without a leading ip, like dns names, get the default.
+    pub fn action(&self, addr: &MultiAddr) -> Action {
+        let mut iter = addr.iter();
+        let mut first = iter.next();
+        if let Some(Protocol::IP6Zone(_)) = first {
+            first = iter.next();
+        }
+
+        let ip = match first {
+            Some(Protocol::IPv4(ip)) => IpAddr::V4(ip),
+            Some(Protocol::IPv6(ip)) => canonical(IpAddr::V6(ip)),
+            _ => return self.default_action,
+        };
+
+        self.rules
+            .iter()
+            .filter(|(net, _)| net.contains(ip))
+            .max_by_key(|(net, _)| net.prefix)
+            .map_or(self.default_action, |(_, action)| *action)
+    }
+
+    pub fn is_allowed(&self, addr: &MultiAddr) -> bool {
+        self.action(addr) == Action::Allow
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn addr(s: &str) -> MultiAddr {
+        MultiAddr::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_longest_prefix() {
+        let mut filter = MultiAddrFilter::new();
+        filter.deny(&addr("/ip4/10.0.0.0/ipcidr/8")).unwrap();
+        filter.allow(&addr("/ip4/10.1.0.0/ipcidr/16")).unwrap();
+        filter.deny(&addr("/ip4/10.1.2.3")).unwrap();
+        filter.deny(&addr("/ip6/fc00::/ipcidr/7")).unwrap();
+
+        for (s, allowed) in [
+            ("/ip4/10.2.0.1/tcp/4001", false),
+            ("/ip4/10.1.0.1/tcp/4001", true),
+            ("/ip4/10.1.2.3/tcp/4001", false),
+            ("/ip4/11.0.0.1/tcp/4001", true),
+            ("/ip6/fd00::1/udp/1/quic-v1", false),
+            ("/ip6/2001:db8::1/tcp/1", true),
+            ("/ip6/::ffff:10.2.0.1/tcp/1", false),
+            ("/ip6zone/eth0/ip6/fd00::1", false),
+            ("/dns4/example.com/tcp/1", true),
+        ] {
+            assert_eq!(filter.is_allowed(&addr(s)), allowed, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_default_action() {
+        let mut filter = MultiAddrFilter::new();
+        filter.default_action = Action::Deny;
+        filter.allow(&addr("/ip4/192.0.2.0/ipcidr/24")).unwrap();
+
+        assert!(filter.is_allowed(&addr("/ip4/192.0.2.7/tcp/1")));
+        assert!(!filter.is_allowed(&addr("/ip4/192.0.3.7/tcp/1")));
+        assert!(!filter.is_allowed(&addr("/dns4/example.com/tcp/1")));
+
+        // the whole space, for both families
+        filter.deny(&addr("/ip4/0.0.0.0/ipcidr/0")).unwrap();
+        filter.allow(&addr("/ip6/::/ipcidr/0")).unwrap();
+        assert!(filter.is_allowed(&addr("/ip4/192.0.2.7")));
+        assert!(!filter.is_allowed(&addr("/ip4/8.8.8.8")));
+        assert!(filter.is_allowed(&addr("/ip6/2001:db8::1")));
+    }
+
+    #[test]
+    fn test_replace_and_remove() {
+        let mut filter = MultiAddrFilter::new();
+        filter.deny(&addr("/ip4/10.0.0.0/ipcidr/8")).unwrap();
+        // host bits are ignored, this is the same network
+        filter.allow(&addr("/ip4/10.9.9.9/ipcidr/8")).unwrap();
+        assert!(filter.is_allowed(&addr("/ip4/10.0.0.1")));
+
+        assert_eq!(
+            filter.remove(&addr("/ip4/10.0.0.0/ipcidr/8")),
+            Ok(Some(Action::Allow))
+        );
+        assert_eq!(filter.remove(&addr("/ip4/10.0.0.0/ipcidr/8")), Ok(None));
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let mut filter = MultiAddrFilter::new();
+
+        assert_eq!(
+            filter.deny(&addr("/ip4/10.0.0.0/ipcidr/33")),
+            Err(Error::InvalidValue("ipcidr"))
+        );
+        for s in [
+            "/dns4/example.com",
+            "/ipcidr/8",
+            "/ip4/10.0.0.0/tcp/1",
+            "/ip4/10.0.0.0/ipcidr/8/tcp/1",
+        ] {
+            assert_eq!(filter.deny(&addr(s)), Err(Error::InvalidCidr), "{}", s);
+        }
+        assert!(filter.allow(&addr("/ip6/::/ipcidr/128")).is_ok());
+    }
+}
@@ -6,11 +6,9 @@ impl<'s> Iterator for ProtocolIter<'s> {
     type Item = Protocol<'s>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.0.is_empty() {
-            return None;
-        }
-
-        let (protocol, len) = Protocol::from_bytes(self.0).expect("invalid multiaddr");
+        // the bytes of a multiaddr are checked when it is built, so this only
+        // stops at the end
+        let (protocol, len) = Protocol::from_bytes(self.0).ok()?;
         self.0 = &self.0[len..];
 
         Some(protocol)
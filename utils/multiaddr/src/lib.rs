@@ -1,12 +1,36 @@
 mod addr;
+mod dns;
 mod errors;
+mod filter;
 mod iter;
+mod mafmt;
+mod net;
 mod protocol;
 mod protocols;
+mod resolve;
+mod scope;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod slice;
+mod url;
 
 pub use errors::*;
 
 pub use addr::*;
+pub use filter::*;
 pub use iter::*;
+pub use mafmt::*;
+pub use net::*;
 pub use protocol::*;
 pub use protocols::*;
+pub use resolve::*;
+pub use scope::*;
+pub use slice::*;
+
+pub fn to_code(val: &str) -> u64 {
+    try_to_code(val).expect("unknown protocol name")
+}
+
+pub fn to_type(val: u64) -> String {
+    try_to_type(val).expect("unknown protocol code").to_string()
+}
@@ -0,0 +1,339 @@
+use crate::{to_code, MultiAddr};
+
+/// A pattern over the protocols of a multiaddr, like go-multiaddr-fmt.
+///
+/// A pattern matches when it consumes every protocol of the address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pattern {
+    /// One protocol with this code.
+    Base(u64),
+    /// Every pattern, one after the other.
+    And(Vec<Pattern>),
+    /// Any of the patterns.
+    Or(Vec<Pattern>),
+    /// The pattern or nothing.
+    Optional(Box<Pattern>),
+    /// The pattern any number of times, including none.
+    Repeat(Box<Pattern>),
+}
+
+fn base(name: &str) -> Pattern {
+    Pattern::Base(to_code(name))
+}
+
+impl Pattern {
+    pub fn matches(&self, addr: &MultiAddr) -> bool {
+        let codes: Vec<u64> = addr.iter().map(|protocol| protocol.code()).collect();
+
+        self.partial_match(&codes).contains(&codes.len())
+    }
+
+    // every number of leading protocols the pattern can consume
+    fn partial_match(&self, codes: &[u64]) -> Vec<usize> {
+        let mut lens = match self {
+            Self::Base(code) => match codes.first() {
+                Some(first) if first == code => vec![1],
+                _ => vec![],
+            },
+            Self::And(patterns) => {
+                let mut lens = vec![0];
+                for pattern in patterns {
+                    lens = lens
+                        .iter()
+                        .flat_map(|len| {
+                            pattern
+                                .partial_match(&codes[*len..])
+                                .into_iter()
+                                .map(move |next| len + next)
+                        })
+                        .collect();
+                }
+
+                lens
+            }
+            Self::Or(patterns) => patterns
+                .iter()
+                .flat_map(|pattern| pattern.partial_match(codes))
+                .collect(),
+            Self::Optional(pattern) => {
+                let mut lens = pattern.partial_match(codes);
+                lens.push(0);
+
+                lens
+            }
+            Self::Repeat(pattern) => {
+                let mut lens = vec![0];
+
+                let mut frontier = vec![0];
+                while let Some(len) = frontier.pop() {
+                    for next in pattern.partial_match(&codes[len..]) {
+                        // patterns consuming nothing would loop forever
+                        if next > 0 && !lens.contains(&(len + next)) {
+                            lens.push(len + next);
+                            frontier.push(len + next);
+                        }
+                    }
+                }
+
+                lens
+            }
+        };
+
+        lens.sort_unstable();
+        lens.dedup();
+        lens
+    }
+
+    /// `/ip4` or `/ip6`.
+    pub fn ip() -> Self {
+        Self::Or(vec![base("ip4"), base("ip6")])
+    }
+
+    /// Any dns name, `/dns`, `/dns4`, `/dns6` or `/dnsaddr`.
+    pub fn dns() -> Self {
+        Self::Or(vec![
+            base("dns"),
+            base("dns4"),
+            base("dns6"),
+            base("dnsaddr"),
+        ])
+    }
+
+    /// An ip or a dns name followed by `/tcp`.
+    pub fn tcp() -> Self {
+        Self::And(vec![Self::Or(vec![Self::ip(), Self::dns()]), base("tcp")])
+    }
+
+    /// An ip or a dns name followed by `/udp`.
+    pub fn udp() -> Self {
+        Self::And(vec![Self::Or(vec![Self::ip(), Self::dns()]), base("udp")])
+    }
+
+    /// The draft quic version, `/udp/<port>/quic`.
+    pub fn quic() -> Self {
+        Self::And(vec![Self::udp(), base("quic")])
+    }
+
+    /// RFC 9000 quic, `/udp/<port>/quic-v1`.
+    pub fn quic_v1() -> Self {
+        Self::And(vec![Self::udp(), base("quic-v1")])
+    }
+
+    /// Plain or secure websockets over tcp.
+    pub fn websocket() -> Self {
+        Self::And(vec![
+            Self::tcp(),
+            Self::Or(vec![base("ws"), Self::tls_websocket()]),
+        ])
+    }
+
+    /// Websockets over tls only, either `/wss` or `/tls[/sni/<name>]/ws`.
+    pub fn websocket_secure() -> Self {
+        Self::And(vec![Self::tcp(), Self::tls_websocket()])
+    }
+
+    fn tls_websocket() -> Self {
+        Self::Or(vec![
+            base("wss"),
+            Self::And(vec![
+                base("tls"),
+                Self::Optional(Box::new(base("sni"))),
+                base("ws"),
+            ]),
+        ])
+    }
+
+    /// Webtransport over quic-v1 with any number of certificate hashes.
+    pub fn webtransport() -> Self {
+        Self::And(vec![
+            Self::quic_v1(),
+            base("webtransport"),
+            Self::Repeat(Box::new(base("certhash"))),
+        ])
+    }
+
+    /// Browser to server webrtc with any number of certificate hashes.
+    pub fn webrtc_direct() -> Self {
+        Self::And(vec![
+            Self::udp(),
+            base("webrtc-direct"),
+            Self::Repeat(Box::new(base("certhash"))),
+        ])
+    }
+
+    /// Any address a node can dial directly.
+    pub fn reachable() -> Self {
+        Self::Or(vec![
+            Self::tcp(),
+            Self::websocket(),
+            Self::quic(),
+            Self::quic_v1(),
+            Self::webtransport(),
+            Self::webrtc_direct(),
+        ])
+    }
+
+    /// A relayed address, `<relay>/p2p/<relay id>/p2p-circuit[/p2p/<id>]`.
+    pub fn circuit() -> Self {
+        Self::And(vec![
+            Self::reachable(),
+            base("p2p"),
+            base("p2p-circuit"),
+            Self::Optional(Box::new(base("p2p"))),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    const CERTHASH: &str = "certhash/uEiDDq4_xNyDorZBH3TlGazyJdOWSwvo4PUo5YHFMrvDE8g";
+    const PEER: &str = "p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC";
+
+    fn check(pattern: Pattern, good: &[&str], bad: &[&str]) {
+        for s in good {
+            let addr = MultiAddr::from_str(s).unwrap();
+            assert!(pattern.matches(&addr), "{} should match", s);
+        }
+        for s in bad {
+            let addr = MultiAddr::from_str(s).unwrap();
+            assert!(!pattern.matches(&addr), "{} should not match", s);
+        }
+    }
+
+    #[test]
+    fn test_transports() {
+        check(
+            Pattern::tcp(),
+            &[
+                "/ip4/1.2.3.4/tcp/80",
+                "/ip6/::1/tcp/0",
+                "/dns4/example.com/tcp/443",
+            ],
+            &[
+                "/ip4/1.2.3.4",
+                "/ip4/1.2.3.4/udp/80",
+                "/ip4/1.2.3.4/tcp/80/ws",
+                "/tcp/80",
+            ],
+        );
+        check(
+            Pattern::udp(),
+            &["/ip4/1.2.3.4/udp/80", "/dns/example.com/udp/53"],
+            &["/ip4/1.2.3.4/tcp/80", "/ip4/1.2.3.4/udp/80/quic-v1"],
+        );
+        check(
+            Pattern::quic(),
+            &["/ip4/1.2.3.4/udp/1234/quic"],
+            &[
+                "/ip4/1.2.3.4/udp/1234/quic-v1",
+                "/ip4/1.2.3.4/tcp/1234/quic",
+            ],
+        );
+        check(
+            Pattern::quic_v1(),
+            &["/ip6/::1/udp/1234/quic-v1"],
+            &["/ip4/1.2.3.4/udp/1234/quic"],
+        );
+    }
+
+    #[test]
+    fn test_websocket() {
+        check(
+            Pattern::websocket(),
+            &[
+                "/ip4/1.2.3.4/tcp/80/ws",
+                "/dns4/example.com/tcp/443/wss",
+                "/dns4/example.com/tcp/443/tls/ws",
+                "/ip4/1.2.3.4/tcp/443/tls/sni/example.com/ws",
+            ],
+            &[
+                "/ip4/1.2.3.4/tcp/80",
+                "/ip4/1.2.3.4/udp/80/ws",
+                "/ip4/1.2.3.4/tcp/80/tls",
+            ],
+        );
+        check(
+            Pattern::websocket_secure(),
+            &[
+                "/dns4/example.com/tcp/443/wss",
+                "/ip4/1.2.3.4/tcp/443/tls/sni/example.com/ws",
+            ],
+            &["/ip4/1.2.3.4/tcp/80/ws"],
+        );
+    }
+
+    #[test]
+    fn test_certhashes() {
+        check(
+            Pattern::webtransport(),
+            &[
+                "/ip4/1.2.3.4/udp/1234/quic-v1/webtransport",
+                &format!("/ip4/1.2.3.4/udp/1234/quic-v1/webtransport/{}", CERTHASH),
+                &format!(
+                    "/ip4/1.2.3.4/udp/1234/quic-v1/webtransport/{0}/{0}",
+                    CERTHASH
+                ),
+            ],
+            &[
+                "/ip4/1.2.3.4/udp/1234/quic/webtransport",
+                &format!("/ip4/1.2.3.4/udp/1234/quic-v1/{}", CERTHASH),
+            ],
+        );
+        check(
+            Pattern::webrtc_direct(),
+            &[&format!("/ip4/1.2.3.4/udp/1234/webrtc-direct/{}", CERTHASH)],
+            &[
+                "/ip4/1.2.3.4/tcp/1234/webrtc-direct",
+                "/ip4/1.2.3.4/udp/1234/webrtc",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_circuit() {
+        check(
+            Pattern::circuit(),
+            &[
+                &format!("/ip4/1.2.3.4/tcp/4001/{}/p2p-circuit", PEER),
+                &format!("/ip4/1.2.3.4/udp/4001/quic-v1/{0}/p2p-circuit/{0}", PEER),
+            ],
+            &[
+                &format!("/ip4/1.2.3.4/tcp/4001/{}", PEER),
+                &format!("/{}/p2p-circuit", PEER),
+                "/ip4/1.2.3.4/tcp/4001/p2p-circuit",
+            ],
+        );
+        check(
+            Pattern::reachable(),
+            &[
+                "/ip4/1.2.3.4/tcp/4001",
+                "/dns4/example.com/tcp/443/wss",
+                "/ip4/1.2.3.4/udp/4001/quic-v1",
+            ],
+            &[&format!("/{}", PEER), "/ip4/1.2.3.4", "/unix/%2Ftmp%2Fsock"],
+        );
+    }
+
+    #[test]
+    fn test_backtracking() {
+        // a greedy matcher would take the optional tcp and fail on the last
+        let pattern = Pattern::And(vec![
+            Pattern::Optional(Box::new(base("tcp"))),
+            Pattern::Repeat(Box::new(base("tcp"))),
+            base("tcp"),
+        ]);
+        check(
+            pattern,
+            &["/tcp/1", "/tcp/1/tcp/2", "/tcp/1/tcp/2/tcp/3"],
+            &["/udp/1"],
+        );
+
+        let nothing = Pattern::Repeat(Box::new(Pattern::And(vec![])));
+        assert!(nothing.matches(&MultiAddr::new()));
+        assert!(!nothing.matches(&MultiAddr::from_str("/tcp/1").unwrap()));
+    }
+}
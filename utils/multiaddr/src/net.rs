@@ -0,0 +1,124 @@
+use std::net::{IpAddr, SocketAddr};
+
+use crate::{MultiAddr, Protocol};
+
+/// The transport protocol carried on top of an ip address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transport {
+    TCP,
+    UDP,
+}
+
+impl From<IpAddr> for Protocol<'_> {
+    fn from(ip: IpAddr) -> Self {
+        match ip {
+            IpAddr::V4(ip) => Protocol::IPv4(ip),
+            IpAddr::V6(ip) => Protocol::IPv6(ip),
+        }
+    }
+}
+
+impl From<IpAddr> for MultiAddr {
+    fn from(ip: IpAddr) -> Self {
+        let mut addr = MultiAddr::new();
+        addr.push_unchecked(ip.into());
+
+        addr
+    }
+}
+
+impl From<(SocketAddr, Transport)> for MultiAddr {
+    fn from((socket, transport): (SocketAddr, Transport)) -> Self {
+        let mut addr = MultiAddr::from(socket.ip());
+        addr.push_unchecked(match transport {
+            Transport::TCP => Protocol::TCP(socket.port()),
+            Transport::UDP => Protocol::UDP(socket.port()),
+        });
+
+        addr
+    }
+}
+
+impl MultiAddr {
+    /// Converts `/ip4|ip6/<ip>/tcp|udp/<port>` to a socket address, a trailing
+    /// `/p2p` is allowed and ignored.
+    ///
+    /// Returns `None` for any other address, transports running on top of
+    /// tcp or udp have to strip their own protocols first.
+    pub fn to_socket_addr(&self) -> Option<(SocketAddr, Transport)> {
+        let mut iter = self.iter();
+
+        let ip = match iter.next()? {
+            Protocol::IPv4(ip) => IpAddr::V4(ip),
+            Protocol::IPv6(ip) => IpAddr::V6(ip),
+            _ => return None,
+        };
+        let (port, transport) = match iter.next()? {
+            Protocol::TCP(port) => (port, Transport::TCP),
+            Protocol::UDP(port) => (port, Transport::UDP),
+            _ => return None,
+        };
+
+        match (iter.next(), iter.next()) {
+            (None, _) | (Some(Protocol::P2P(_)), None) => {
+                Some((SocketAddr::new(ip, port), transport))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_socket_addr() {
+        for (s, socket, transport) in [
+            ("/ip4/127.0.0.1/tcp/4001", "127.0.0.1:4001", Transport::TCP),
+            ("/ip4/10.0.0.1/udp/53", "10.0.0.1:53", Transport::UDP),
+            ("/ip6/::1/tcp/443", "[::1]:443", Transport::TCP),
+        ] {
+            let socket = SocketAddr::from_str(socket).unwrap();
+            let addr = MultiAddr::from_str(s).unwrap();
+
+            assert_eq!(MultiAddr::from((socket, transport)), addr);
+            assert_eq!(addr.to_socket_addr(), Some((socket, transport)));
+        }
+
+        let addr = MultiAddr::from_str(
+            "/ip4/127.0.0.1/tcp/4001/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC",
+        )
+        .unwrap();
+        assert_eq!(
+            addr.to_socket_addr(),
+            Some(("127.0.0.1:4001".parse().unwrap(), Transport::TCP))
+        );
+
+        for s in [
+            "/ip4/127.0.0.1",
+            "/tcp/4001",
+            "/ip4/127.0.0.1/tcp/4001/ws",
+            "/ip4/127.0.0.1/udp/4001/quic-v1",
+            "/dns4/example.com/tcp/443",
+            "/ip4/127.0.0.1/tcp/4001/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC/p2p-circuit",
+        ] {
+            assert_eq!(MultiAddr::from_str(s).unwrap().to_socket_addr(), None, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_ip_addr() {
+        let ip = IpAddr::from_str("192.168.1.1").unwrap();
+        assert_eq!(MultiAddr::from(ip).to_string(), "/ip4/192.168.1.1");
+
+        let ip = IpAddr::from_str("fe80::1").unwrap();
+        assert_eq!(
+            Protocol::from(ip),
+            Protocol::IPv6("fe80::1".parse().unwrap())
+        );
+        assert_eq!(MultiAddr::from(ip).to_string(), "/ip6/fe80::1");
+    }
+}
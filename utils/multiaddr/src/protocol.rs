@@ -1,37 +1,50 @@
 use std::{
     borrow::Cow,
-    io::{Cursor, Write},
+    fmt,
+    io::Cursor,
     net::{Ipv4Addr, Ipv6Addr},
-    str,
+    str::{self, FromStr},
 };
 
-use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
-use multicodec::to_type;
+use byteorder::{BigEndian, ReadBytesExt};
+use multibase::{Decoder, Encoder};
 use varint::Varint;
 
-use crate::{to_code, UnknownProtocolError};
+use crate::{to_code, try_to_code, try_to_type, Error};
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Protocol<'s> {
     DCCP(u16),
     DNS(Cow<'s, str>),
     DNS4(Cow<'s, str>),
     DNS6(Cow<'s, str>),
     DNSAddr(Cow<'s, str>),
+    Garlic32(Cow<'s, [u8]>),
+    Garlic64(Cow<'s, [u8]>),
     HTTP,
+    HTTPPath(Cow<'s, str>),
     HTTPS,
+    IP6Zone(Cow<'s, str>),
+    IPCidr(u8),
     IPv4(Ipv4Addr),
     IPv6(Ipv6Addr),
+    Onion([u8; 10], u16),
+    Onion3([u8; 35], u16),
+    P2PStardust,
     P2PWebRTCDirect,
     P2PWebRTCStar,
     WebRTC,
-    CertHash(Vec<u8>),
+    WebRTCDirect,
+    CertHash(Cow<'s, [u8]>),
     P2PWebSocketStar,
     Memory(u64),
-    P2P(Vec<u8>),
+    P2P(Cow<'s, [u8]>),
     P2PCircuit,
+    PlaintextV2,
     QUIC,
     QUICv1,
     SCTP(u16),
+    SNI(Cow<'s, str>),
     TCP(u16),
     TLS,
     Noise,
@@ -40,46 +53,114 @@ pub enum Protocol<'s> {
     Unix(Cow<'s, str>),
     UTP,
     WebTransport,
-    WS(Cow<'s, str>),
-    WSS(Cow<'s, str>),
+    WS,
+    WSS,
+}
+
+fn read_varint(input: &[u8]) -> Result<(u64, usize), Error> {
+    match u64::decode_varint(input) {
+        Some(v) => Ok(v),
+        None if input.len() < 10 && input.iter().all(|b| b & 0x80 != 0) => {
+            Err(Error::UnexpectedEnd)
+        }
+        None => Err(Error::InvalidVarint),
+    }
+}
+
+// reads a varint length followed by that many bytes
+fn read_len_prefixed(input: &[u8]) -> Result<(&[u8], usize), Error> {
+    let (len, off) = read_varint(input)?;
+    let end = usize::try_from(len)
+        .ok()
+        .and_then(|len| len.checked_add(off))
+        .ok_or(Error::UnexpectedEnd)?;
+    let bytes = input.get(off..end).ok_or(Error::UnexpectedEnd)?;
+
+    Ok((bytes, end))
+}
+
+// an onion address is the service id followed by a 16 bits port
+fn read_onion<const N: usize>(input: &[u8]) -> Result<([u8; N], u16), Error> {
+    let bytes = input.get(..N + 2).ok_or(Error::UnexpectedEnd)?;
+
+    let mut host = [0u8; N];
+    host.copy_from_slice(&bytes[..N]);
+
+    Ok((host, u16::from_be_bytes([bytes[N], bytes[N + 1]])))
+}
+
+// the prefix length of an ip6 network at most
+const MAX_CIDR: u8 = 128;
+
+fn decode_onion<const N: usize>(input: &str) -> Option<([u8; N], u16)> {
+    let (host, port) = input.split_once(':')?;
+
+    let host = multibase::Base32::decode(&format!("b{}", host.to_ascii_lowercase()));
+    let port = port.parse().ok()?;
+
+    Some((host.try_into().ok()?, port))
+}
+
+fn encode_onion(host: &[u8], port: u16) -> String {
+    format!("{}:{}", &multibase::Base32::encode(host)[1..], port)
+}
+
+// i2p uses base64 with - and ~ in place of + and /
+fn decode_garlic64(input: &str) -> Option<Vec<u8>> {
+    if input.contains('+') {
+        return None;
+    }
+
+    Some(multibase::Base64Pad::decode(&format!(
+        "M{}",
+        input.replace('-', "+").replace('~', "/")
+    )))
+}
+
+fn encode_garlic64(input: &[u8]) -> String {
+    multibase::Base64Pad::encode(input)[1..]
+        .replace('+', "-")
+        .replace('/', "~")
+}
+
+// a full i2p destination, never shorter than 386 bytes
+fn is_garlic64(bytes: &[u8]) -> bool {
+    bytes.len() >= 386
+}
+
+// either a 32 bytes hash or an encrypted leaseset2 of at least 35 bytes
+fn is_garlic32(bytes: &[u8]) -> bool {
+    bytes.len() == 32 || bytes.len() >= 35
 }
 
 macro_rules! protocol_write_type_bytes {
     ($w: expr, $type: expr) => {{
-        let mut buf = [0u8; 4];
+        let mut buf = [0u8; 10];
         let len = to_code($type).encode_varint(&mut buf);
-        $w.write_all(&buf[..len]).or(Err(UnknownProtocolError))?;
+        $w.extend_from_slice(&buf[..len]);
     }};
 }
 
 macro_rules! protocol_read_str {
     ($type: expr, $len: expr, $input: expr) => {{
-        let (strlen, stroff) = u64::decode_varint($input).ok_or(UnknownProtocolError)?;
-        let cnt = str::from_utf8(&$input[stroff..stroff + strlen as usize])
-            .or(Err(UnknownProtocolError))?;
+        let (bytes, off) = read_len_prefixed($input)?;
+        let cnt = str::from_utf8(bytes).or(Err(Error::InvalidUtf8))?;
 
-        Ok(($type(Cow::Borrowed(cnt)), $len + stroff + strlen as usize))
+        Ok(($type(Cow::Borrowed(cnt)), $len + off))
     }};
 }
 
 macro_rules! protocol_write_str {
     ($w: expr, $type: expr, $val: expr) => {{
-        protocol_write_type_bytes!($w, $type);
-
-        let mut buf = [0u8; 8];
-        let len = ($val.len() as u64).encode_varint(&mut buf);
-        $w.write_all(&buf[..len]).or(Err(UnknownProtocolError))?;
-        $w.write_all($val.as_bytes())
-            .or(Err(UnknownProtocolError))?;
+        protocol_write_vec!($w, $type, $val.as_bytes());
     }};
 }
 
 macro_rules! protocol_read_vec {
     ($type: expr, $len: expr, $input: expr) => {{
-        let (veclen, vecoff) = u64::decode_varint($input).ok_or(UnknownProtocolError)?;
-        let cnt = Vec::from(&$input[vecoff..vecoff + veclen as usize]);
+        let (bytes, off) = read_len_prefixed($input)?;
 
-        Ok(($type(cnt), $len + vecoff + veclen as usize))
+        Ok(($type(Cow::Borrowed(bytes)), $len + off))
     }};
 }
 
@@ -87,10 +168,10 @@ macro_rules! protocol_write_vec {
     ($w: expr, $type: expr, $val: expr) => {{
         protocol_write_type_bytes!($w, $type);
 
-        let mut buf = [0u8; 8];
+        let mut buf = [0u8; 10];
         let len = ($val.len() as u64).encode_varint(&mut buf);
-        $w.write_all(&buf[..len]).or(Err(UnknownProtocolError))?;
-        $w.write_all($val).or(Err(UnknownProtocolError))?;
+        $w.extend_from_slice(&buf[..len]);
+        $w.extend_from_slice($val);
     }};
 }
 
@@ -99,7 +180,7 @@ macro_rules! protocol_read_u16 {
         let mut reader = Cursor::new($input);
         let val = reader
             .read_u16::<BigEndian>()
-            .or(Err(UnknownProtocolError))?;
+            .or(Err(Error::UnexpectedEnd))?;
 
         Ok(($type(val), $len + 2))
     }};
@@ -108,8 +189,7 @@ macro_rules! protocol_read_u16 {
 macro_rules! protocol_write_u16 {
     ($w: expr, $type: expr, $val: expr) => {{
         protocol_write_type_bytes!($w, $type);
-        $w.write_u16::<BigEndian>($val)
-            .or(Err(UnknownProtocolError))?;
+        $w.extend_from_slice(&$val.to_be_bytes());
     }};
 }
 
@@ -118,7 +198,7 @@ macro_rules! protocol_read_u64 {
         let mut reader = Cursor::new($input);
         let val = reader
             .read_u64::<BigEndian>()
-            .or(Err(UnknownProtocolError))?;
+            .or(Err(Error::UnexpectedEnd))?;
 
         Ok(($type(val), $len + 8))
     }};
@@ -127,21 +207,33 @@ macro_rules! protocol_read_u64 {
 macro_rules! protocol_write_u64 {
     ($w: expr, $type: expr, $val: expr) => {{
         protocol_write_type_bytes!($w, $type);
-        $w.write_u64::<BigEndian>($val)
-            .or(Err(UnknownProtocolError))?;
+        $w.extend_from_slice(&$val.to_be_bytes());
     }};
 }
 
 impl<'s> Protocol<'s> {
-    pub(crate) fn from_bytes(input: &'s [u8]) -> Result<(Self, usize), UnknownProtocolError> {
-        let (id, len) = u64::decode_varint(input).ok_or(UnknownProtocolError)?;
+    /// Decodes the protocol at the start of `input`, returning it along with
+    /// the number of bytes it took.
+    pub fn from_bytes(input: &'s [u8]) -> Result<(Self, usize), Error> {
+        let (protocol, len) = Self::read_bytes(input)?;
+        protocol.check()?;
+
+        Ok((protocol, len))
+    }
+
+    fn read_bytes(input: &'s [u8]) -> Result<(Self, usize), Error> {
+        let (id, len) = read_varint(input)?;
         let input = &input[len..];
 
-        match to_type(id).as_str() {
-            "ip4" => Ok((
-                Protocol::IPv4(Ipv4Addr::new(input[0], input[1], input[2], input[3])),
-                len + 4,
-            )),
+        match try_to_type(id).ok_or(Error::UnknownProtocol(id))? {
+            "ip4" => {
+                let octets = input.get(..4).ok_or(Error::UnexpectedEnd)?;
+
+                Ok((
+                    Protocol::IPv4(Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3])),
+                    len + 4,
+                ))
+            }
             "ip6" => {
                 let mut reader = Cursor::new(input);
 
@@ -149,7 +241,7 @@ impl<'s> Protocol<'s> {
                 for x in seg.iter_mut() {
                     *x = reader
                         .read_u16::<BigEndian>()
-                        .or(Err(UnknownProtocolError))?;
+                        .or(Err(Error::UnexpectedEnd))?;
                 }
 
                 Ok((
@@ -169,13 +261,34 @@ impl<'s> Protocol<'s> {
             "dns6" => protocol_read_str!(Protocol::DNS6, len, input),
             "dnsaddr" => protocol_read_str!(Protocol::DNSAddr, len, input),
             "unix" => protocol_read_str!(Protocol::Unix, len, input),
-            "ws" => protocol_read_str!(Protocol::WS, len, input),
-            "wss" => protocol_read_str!(Protocol::WSS, len, input),
+            "sni" => protocol_read_str!(Protocol::SNI, len, input),
+            "http-path" => protocol_read_str!(Protocol::HTTPPath, len, input),
+            "ip6zone" => protocol_read_str!(Protocol::IP6Zone, len, input),
+            "ipcidr" => {
+                let mask = *input.first().ok_or(Error::UnexpectedEnd)?;
+
+                Ok((Protocol::IPCidr(mask), len + 1))
+            }
+            "onion" => {
+                let (host, port) = read_onion(input)?;
+
+                Ok((Protocol::Onion(host, port), len + 12))
+            }
+            "onion3" => {
+                let (host, port) = read_onion(input)?;
+
+                Ok((Protocol::Onion3(host, port), len + 37))
+            }
+            "garlic64" => protocol_read_vec!(Protocol::Garlic64, len, input),
+            "garlic32" => protocol_read_vec!(Protocol::Garlic32, len, input),
             "certhash" => protocol_read_vec!(Protocol::CertHash, len, input),
             "p2p" => protocol_read_vec!(Protocol::P2P, len, input),
             "p2p-webrtc-star" => Ok((Protocol::P2PWebRTCStar, len)),
             "p2p-webrtc-direct" => Ok((Protocol::P2PWebRTCDirect, len)),
+            "p2p-stardust" => Ok((Protocol::P2PStardust, len)),
             "webrtc" => Ok((Protocol::WebRTC, len)),
+            "webrtc-direct" => Ok((Protocol::WebRTCDirect, len)),
+            "plaintextv2" => Ok((Protocol::PlaintextV2, len)),
             "p2p-circuit" => Ok((Protocol::P2PCircuit, len)),
             "udt" => Ok((Protocol::UDT, len)),
             "utp" => Ok((Protocol::UTP, len)),
@@ -187,20 +300,22 @@ impl<'s> Protocol<'s> {
             "webtransport" => Ok((Protocol::WebTransport, len)),
             "p2p-websocket-star" => Ok((Protocol::P2PWebSocketStar, len)),
             "http" => Ok((Protocol::HTTP, len)),
+            "ws" => Ok((Protocol::WS, len)),
+            "wss" => Ok((Protocol::WSS, len)),
 
-            _ => Err(UnknownProtocolError),
+            _ => Err(Error::UnknownProtocol(id)),
         }
     }
 
-    pub(crate) fn write_bytes<W: Write>(&self, w: &mut W) -> Result<(), UnknownProtocolError> {
+    pub(crate) fn write_bytes(&self, w: &mut Vec<u8>) {
         match self {
             Self::IPv4(addr) => {
                 protocol_write_type_bytes!(w, "ip4");
-                w.write_all(&addr.octets()).or(Err(UnknownProtocolError))?;
+                w.extend_from_slice(&addr.octets());
             }
             Self::IPv6(addr) => {
                 protocol_write_type_bytes!(w, "ip6");
-                w.write_all(&addr.octets()).or(Err(UnknownProtocolError))?;
+                w.extend_from_slice(&addr.octets());
             }
             Self::DCCP(val) => protocol_write_u16!(w, "dccp", *val),
             Self::SCTP(val) => protocol_write_u16!(w, "sctp", *val),
@@ -212,13 +327,33 @@ impl<'s> Protocol<'s> {
             Self::DNS6(val) => protocol_write_str!(w, "dns6", val),
             Self::DNSAddr(val) => protocol_write_str!(w, "dnsaddr", val),
             Self::Unix(val) => protocol_write_str!(w, "unix", val),
-            Self::WS(val) => protocol_write_str!(w, "ws", val),
-            Self::WSS(val) => protocol_write_str!(w, "wss", val),
+            Self::SNI(val) => protocol_write_str!(w, "sni", val),
+            Self::HTTPPath(val) => protocol_write_str!(w, "http-path", val),
+            Self::IP6Zone(val) => protocol_write_str!(w, "ip6zone", val),
+            Self::IPCidr(mask) => {
+                protocol_write_type_bytes!(w, "ipcidr");
+                w.push(*mask);
+            }
+            Self::Onion(host, port) => {
+                protocol_write_type_bytes!(w, "onion");
+                w.extend_from_slice(host);
+                w.extend_from_slice(&port.to_be_bytes());
+            }
+            Self::Onion3(host, port) => {
+                protocol_write_type_bytes!(w, "onion3");
+                w.extend_from_slice(host);
+                w.extend_from_slice(&port.to_be_bytes());
+            }
+            Self::Garlic64(val) => protocol_write_vec!(w, "garlic64", val),
+            Self::Garlic32(val) => protocol_write_vec!(w, "garlic32", val),
             Self::CertHash(val) => protocol_write_vec!(w, "certhash", val),
             Self::P2P(val) => protocol_write_vec!(w, "p2p", val),
             Self::P2PWebRTCStar => protocol_write_type_bytes!(w, "p2p-webrtc-star"),
             Self::P2PWebRTCDirect => protocol_write_type_bytes!(w, "p2p-webrtc-direct"),
+            Self::P2PStardust => protocol_write_type_bytes!(w, "p2p-stardust"),
             Self::WebRTC => protocol_write_type_bytes!(w, "webrtc"),
+            Self::WebRTCDirect => protocol_write_type_bytes!(w, "webrtc-direct"),
+            Self::PlaintextV2 => protocol_write_type_bytes!(w, "plaintextv2"),
             Self::P2PCircuit => protocol_write_type_bytes!(w, "p2p-circuit"),
             Self::UDT => protocol_write_type_bytes!(w, "udt"),
             Self::UTP => protocol_write_type_bytes!(w, "utp"),
@@ -230,9 +365,9 @@ impl<'s> Protocol<'s> {
             Self::WebTransport => protocol_write_type_bytes!(w, "webtransport"),
             Self::P2PWebSocketStar => protocol_write_type_bytes!(w, "p2p-websocket-star"),
             Self::HTTP => protocol_write_type_bytes!(w, "http"),
+            Self::WS => protocol_write_type_bytes!(w, "ws"),
+            Self::WSS => protocol_write_type_bytes!(w, "wss"),
         }
-
-        Ok(())
     }
 
     pub(crate) fn acquire<'b>(self) -> Protocol<'b> {
@@ -249,13 +384,22 @@ impl<'s> Protocol<'s> {
             Self::DNS6(v) => Protocol::DNS6(Cow::Owned(v.into_owned())),
             Self::DNSAddr(v) => Protocol::DNSAddr(Cow::Owned(v.into_owned())),
             Self::Unix(v) => Protocol::Unix(Cow::Owned(v.into_owned())),
-            Self::WS(v) => Protocol::WS(Cow::Owned(v.into_owned())),
-            Self::WSS(v) => Protocol::WSS(Cow::Owned(v.into_owned())),
-            Self::CertHash(v) => Protocol::CertHash(v),
-            Self::P2P(v) => Protocol::P2P(v),
+            Self::SNI(v) => Protocol::SNI(Cow::Owned(v.into_owned())),
+            Self::HTTPPath(v) => Protocol::HTTPPath(Cow::Owned(v.into_owned())),
+            Self::IP6Zone(v) => Protocol::IP6Zone(Cow::Owned(v.into_owned())),
+            Self::IPCidr(v) => Protocol::IPCidr(v),
+            Self::Onion(host, port) => Protocol::Onion(host, port),
+            Self::Onion3(host, port) => Protocol::Onion3(host, port),
+            Self::Garlic64(v) => Protocol::Garlic64(Cow::Owned(v.into_owned())),
+            Self::Garlic32(v) => Protocol::Garlic32(Cow::Owned(v.into_owned())),
+            Self::CertHash(v) => Protocol::CertHash(Cow::Owned(v.into_owned())),
+            Self::P2P(v) => Protocol::P2P(Cow::Owned(v.into_owned())),
             Self::P2PWebRTCStar => Protocol::P2PWebRTCStar,
             Self::P2PWebRTCDirect => Protocol::P2PWebRTCDirect,
+            Self::P2PStardust => Protocol::P2PStardust,
             Self::WebRTC => Protocol::WebRTC,
+            Self::WebRTCDirect => Protocol::WebRTCDirect,
+            Self::PlaintextV2 => Protocol::PlaintextV2,
             Self::P2PCircuit => Protocol::P2PCircuit,
             Self::UDT => Protocol::UDT,
             Self::UTP => Protocol::UTP,
@@ -267,6 +411,291 @@ impl<'s> Protocol<'s> {
             Self::WebTransport => Protocol::WebTransport,
             Self::P2PWebSocketStar => Protocol::P2PWebSocketStar,
             Self::HTTP => Protocol::HTTP,
+            Self::WS => Protocol::WS,
+            Self::WSS => Protocol::WSS,
+        }
+    }
+}
+
+// libp2p-key, the codec of a peer id in its CIDv1 form
+const LIBP2P_KEY: u64 = 0x72;
+
+fn percent_encode(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '%' => output.push_str("%25"),
+            '/' => output.push_str("%2F"),
+            _ => output.push(c),
+        }
+    }
+
+    output
+}
+
+pub(crate) fn percent_decode(input: &str) -> Option<String> {
+    let mut output = Vec::with_capacity(input.len());
+
+    let mut bytes = input.bytes();
+    while let Some(b) = bytes.next() {
+        if b != b'%' {
+            output.push(b);
+            continue;
+        }
+
+        let hex = [bytes.next()?, bytes.next()?];
+        output.push(u8::from_str_radix(str::from_utf8(&hex).ok()?, 16).ok()?);
+    }
+
+    String::from_utf8(output).ok()
+}
+
+// a peer id is either a base58btc multihash or a multibase CIDv1
+fn decode_peer_id(input: &str) -> Option<Vec<u8>> {
+    if input.starts_with('Q') || input.starts_with('1') {
+        let hash = multibase::Base58Bitcoin::decode(&format!("z{}", input));
+        return (!hash.is_empty()).then_some(hash);
+    }
+
+    let cid = multibase::multibase_decode(input);
+    let (version, version_len) = u64::decode_varint(&cid)?;
+    let (codec, codec_len) = u64::decode_varint(&cid[version_len..])?;
+    let hash = &cid[version_len + codec_len..];
+    if version != 1 || codec != LIBP2P_KEY || hash.is_empty() {
+        return None;
+    }
+
+    Some(hash.to_vec())
+}
+
+impl Protocol<'_> {
+    /// Returns the name of the protocol in the multiaddr table.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::DCCP(_) => "dccp",
+            Self::DNS(_) => "dns",
+            Self::DNS4(_) => "dns4",
+            Self::DNS6(_) => "dns6",
+            Self::DNSAddr(_) => "dnsaddr",
+            Self::Garlic32(_) => "garlic32",
+            Self::Garlic64(_) => "garlic64",
+            Self::HTTP => "http",
+            Self::HTTPPath(_) => "http-path",
+            Self::HTTPS => "https",
+            Self::IP6Zone(_) => "ip6zone",
+            Self::IPCidr(_) => "ipcidr",
+            Self::IPv4(_) => "ip4",
+            Self::IPv6(_) => "ip6",
+            Self::Onion(..) => "onion",
+            Self::Onion3(..) => "onion3",
+            Self::P2PStardust => "p2p-stardust",
+            Self::P2PWebRTCDirect => "p2p-webrtc-direct",
+            Self::P2PWebRTCStar => "p2p-webrtc-star",
+            Self::WebRTC => "webrtc",
+            Self::WebRTCDirect => "webrtc-direct",
+            Self::CertHash(_) => "certhash",
+            Self::P2PWebSocketStar => "p2p-websocket-star",
+            Self::Memory(_) => "memory",
+            Self::P2P(_) => "p2p",
+            Self::P2PCircuit => "p2p-circuit",
+            Self::PlaintextV2 => "plaintextv2",
+            Self::QUIC => "quic",
+            Self::QUICv1 => "quic-v1",
+            Self::SCTP(_) => "sctp",
+            Self::SNI(_) => "sni",
+            Self::TCP(_) => "tcp",
+            Self::TLS => "tls",
+            Self::Noise => "noise",
+            Self::UDP(_) => "udp",
+            Self::UDT => "udt",
+            Self::Unix(_) => "unix",
+            Self::UTP => "utp",
+            Self::WebTransport => "webtransport",
+            Self::WS => "ws",
+            Self::WSS => "wss",
+        }
+    }
+
+    /// Returns the code of the protocol in the multiaddr table.
+    pub fn code(&self) -> u64 {
+        to_code(self.name())
+    }
+
+    /// Checks the values their types don't constrain, so that however a
+    /// multiaddr is built its bytes decode and its string parses back.
+    pub(crate) fn check(&self) -> Result<(), Error> {
+        let valid = match self {
+            Self::IPCidr(mask) => *mask <= MAX_CIDR,
+            Self::Onion(_, port) | Self::Onion3(_, port) => *port != 0,
+            Self::Garlic32(bytes) => is_garlic32(bytes),
+            Self::Garlic64(bytes) => is_garlic64(bytes),
+            Self::IP6Zone(zone) => !zone.is_empty() && !zone.contains('/'),
+            // written without escaping in the string form
+            Self::DNS(name)
+            | Self::DNS4(name)
+            | Self::DNS6(name)
+            | Self::DNSAddr(name)
+            | Self::SNI(name) => !name.contains('/'),
+            _ => true,
+        };
+
+        match valid {
+            true => Ok(()),
+            false => Err(Error::InvalidValue(self.name())),
+        }
+    }
+
+    /// Parses one protocol from the `/`-separated components of a multiaddr.
+    pub(crate) fn from_str_parts<'a, I>(parts: &mut I) -> Result<Protocol<'static>, Error>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let name = parts.next().ok_or(Error::InvalidMultiAddr)?;
+        // resolving through the table maps aliases such as ipfs to their
+        // canonical name
+        let name = try_to_code(name)
+            .and_then(try_to_type)
+            .ok_or_else(|| Error::UnknownProtocolName(name.to_string()))?;
+
+        let mut value = || parts.next().ok_or(Error::MissingValue(name));
+        let invalid = || Error::InvalidValue(name);
+
+        let protocol = match name {
+            "ip4" => Protocol::IPv4(value()?.parse().map_err(|_| invalid())?),
+            "ip6" => Protocol::IPv6(value()?.parse().map_err(|_| invalid())?),
+            "dccp" => Protocol::DCCP(value()?.parse().map_err(|_| invalid())?),
+            "sctp" => Protocol::SCTP(value()?.parse().map_err(|_| invalid())?),
+            "tcp" => Protocol::TCP(value()?.parse().map_err(|_| invalid())?),
+            "udp" => Protocol::UDP(value()?.parse().map_err(|_| invalid())?),
+            "memory" => Protocol::Memory(value()?.parse().map_err(|_| invalid())?),
+            "dns" => Protocol::DNS(Cow::Owned(value()?.to_string())),
+            "dns4" => Protocol::DNS4(Cow::Owned(value()?.to_string())),
+            "dns6" => Protocol::DNS6(Cow::Owned(value()?.to_string())),
+            "dnsaddr" => Protocol::DNSAddr(Cow::Owned(value()?.to_string())),
+            "unix" => Protocol::Unix(Cow::Owned(percent_decode(value()?).ok_or_else(invalid)?)),
+            "sni" => Protocol::SNI(Cow::Owned(value()?.to_string())),
+            "http-path" => {
+                Protocol::HTTPPath(Cow::Owned(percent_decode(value()?).ok_or_else(invalid)?))
+            }
+            "ip6zone" => Protocol::IP6Zone(Cow::Owned(value()?.to_string())),
+            "ipcidr" => Protocol::IPCidr(value()?.parse().map_err(|_| invalid())?),
+            "onion" => {
+                let (host, port) = decode_onion(value()?).ok_or_else(invalid)?;
+                Protocol::Onion(host, port)
+            }
+            "onion3" => {
+                let (host, port) = decode_onion(value()?).ok_or_else(invalid)?;
+                Protocol::Onion3(host, port)
+            }
+            "garlic64" => {
+                Protocol::Garlic64(Cow::Owned(decode_garlic64(value()?).ok_or_else(invalid)?))
+            }
+            "garlic32" => Protocol::Garlic32(Cow::Owned(multibase::Base32::decode(&format!(
+                "b{}",
+                value()?
+            )))),
+            "certhash" => {
+                let hash = multibase::multibase_decode(value()?);
+                if hash.is_empty() {
+                    return Err(invalid());
+                }
+
+                Protocol::CertHash(Cow::Owned(hash))
+            }
+            "p2p" => Protocol::P2P(Cow::Owned(decode_peer_id(value()?).ok_or_else(invalid)?)),
+            "p2p-webrtc-star" => Protocol::P2PWebRTCStar,
+            "p2p-webrtc-direct" => Protocol::P2PWebRTCDirect,
+            "p2p-stardust" => Protocol::P2PStardust,
+            "webrtc" => Protocol::WebRTC,
+            "webrtc-direct" => Protocol::WebRTCDirect,
+            "plaintextv2" => Protocol::PlaintextV2,
+            "p2p-circuit" => Protocol::P2PCircuit,
+            "udt" => Protocol::UDT,
+            "utp" => Protocol::UTP,
+            "https" => Protocol::HTTPS,
+            "tls" => Protocol::TLS,
+            "noise" => Protocol::Noise,
+            "quic" => Protocol::QUIC,
+            "quic-v1" => Protocol::QUICv1,
+            "webtransport" => Protocol::WebTransport,
+            "p2p-websocket-star" => Protocol::P2PWebSocketStar,
+            "http" => Protocol::HTTP,
+            "ws" => Protocol::WS,
+            "wss" => Protocol::WSS,
+
+            _ => return Err(Error::UnknownProtocolName(name.to_string())),
+        };
+        protocol.check()?;
+
+        Ok(protocol)
+    }
+}
+
+impl FromStr for Protocol<'static> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s
+            .strip_prefix('/')
+            .ok_or(Error::InvalidMultiAddr)?
+            .split('/');
+
+        let protocol = Protocol::from_str_parts(&mut parts)?;
+        if parts.next().is_some() {
+            return Err(Error::InvalidMultiAddr);
+        }
+
+        Ok(protocol)
+    }
+}
+
+impl fmt::Display for Protocol<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IPv4(addr) => write!(f, "/ip4/{}", addr),
+            Self::IPv6(addr) => write!(f, "/ip6/{}", addr),
+            Self::DCCP(val) => write!(f, "/dccp/{}", val),
+            Self::SCTP(val) => write!(f, "/sctp/{}", val),
+            Self::TCP(val) => write!(f, "/tcp/{}", val),
+            Self::UDP(val) => write!(f, "/udp/{}", val),
+            Self::Memory(val) => write!(f, "/memory/{}", val),
+            Self::DNS(val) => write!(f, "/dns/{}", val),
+            Self::DNS4(val) => write!(f, "/dns4/{}", val),
+            Self::DNS6(val) => write!(f, "/dns6/{}", val),
+            Self::DNSAddr(val) => write!(f, "/dnsaddr/{}", val),
+            Self::Unix(val) => write!(f, "/unix/{}", percent_encode(val)),
+            Self::SNI(val) => write!(f, "/sni/{}", val),
+            Self::HTTPPath(val) => write!(f, "/http-path/{}", percent_encode(val)),
+            Self::IP6Zone(val) => write!(f, "/ip6zone/{}", val),
+            Self::IPCidr(mask) => write!(f, "/ipcidr/{}", mask),
+            Self::Onion(host, port) => write!(f, "/onion/{}", encode_onion(host, *port)),
+            Self::Onion3(host, port) => write!(f, "/onion3/{}", encode_onion(host, *port)),
+            Self::Garlic64(val) => write!(f, "/garlic64/{}", encode_garlic64(val)),
+            Self::Garlic32(val) => write!(f, "/garlic32/{}", &multibase::Base32::encode(val)[1..]),
+            Self::CertHash(val) => write!(f, "/certhash/{}", multibase::Base64URL::encode(val)),
+            Self::P2P(val) => {
+                let encoded = multibase::Base58Bitcoin::encode(val);
+                write!(f, "/p2p/{}", &encoded[1..])
+            }
+            Self::P2PWebRTCStar => f.write_str("/p2p-webrtc-star"),
+            Self::P2PWebRTCDirect => f.write_str("/p2p-webrtc-direct"),
+            Self::P2PStardust => f.write_str("/p2p-stardust"),
+            Self::WebRTC => f.write_str("/webrtc"),
+            Self::WebRTCDirect => f.write_str("/webrtc-direct"),
+            Self::PlaintextV2 => f.write_str("/plaintextv2"),
+            Self::P2PCircuit => f.write_str("/p2p-circuit"),
+            Self::UDT => f.write_str("/udt"),
+            Self::UTP => f.write_str("/utp"),
+            Self::HTTPS => f.write_str("/https"),
+            Self::TLS => f.write_str("/tls"),
+            Self::Noise => f.write_str("/noise"),
+            Self::QUIC => f.write_str("/quic"),
+            Self::QUICv1 => f.write_str("/quic-v1"),
+            Self::WebTransport => f.write_str("/webtransport"),
+            Self::P2PWebSocketStar => f.write_str("/p2p-websocket-star"),
+            Self::HTTP => f.write_str("/http"),
+            Self::WS => f.write_str("/ws"),
+            Self::WSS => f.write_str("/wss"),
         }
     }
 }
@@ -1,90 +1,94 @@
-pub fn to_type(val: u64) -> String {
+pub fn try_to_type(val: u64) -> Option<&'static str> {
     match val {
-        4 => "ip4",
-        6 => "tcp",
-        273 => "udp",
-        33 => "dccp",
-        41 => "ip6",
-        42 => "ip6zone",
-        43 => "ipcidr",
-        53 => "dns",
-        54 => "dns4",
-        55 => "dns6",
-        56 => "dnsaddr",
-        132 => "sctp",
-        301 => "udt",
-        302 => "utp",
-        400 => "unix",
-        421 => "p2p",
-        421 => "ipfs",
-        444 => "onion",
-        445 => "onion3",
-        446 => "garlic64",
-        447 => "garlic32",
-        448 => "tls",
-        449 => "sni",
-        454 => "noise",
-        460 => "quic",
-        461 => "quic-v1",
-        465 => "webtransport",
-        466 => "certhash",
-        480 => "http",
-        443 => "https",
-        477 => "ws",
-        478 => "wss",
-        479 => "p2p-websocket-star",
-        277 => "p2p-stardust",
-        275 => "p2p-webrtc-star",
-        276 => "p2p-webrtc-direct",
-        280 => "webrtc",
-        290 => "p2p-circuit",
-        777 => "memory",
-        _ => unreachable!(),
+        4 => Some("ip4"),
+        6 => Some("tcp"),
+        273 => Some("udp"),
+        33 => Some("dccp"),
+        41 => Some("ip6"),
+        42 => Some("ip6zone"),
+        43 => Some("ipcidr"),
+        53 => Some("dns"),
+        54 => Some("dns4"),
+        55 => Some("dns6"),
+        56 => Some("dnsaddr"),
+        132 => Some("sctp"),
+        301 => Some("udt"),
+        302 => Some("utp"),
+        400 => Some("unix"),
+        421 => Some("p2p"),
+        444 => Some("onion"),
+        445 => Some("onion3"),
+        446 => Some("garlic64"),
+        447 => Some("garlic32"),
+        448 => Some("tls"),
+        449 => Some("sni"),
+        454 => Some("noise"),
+        460 => Some("quic"),
+        461 => Some("quic-v1"),
+        465 => Some("webtransport"),
+        466 => Some("certhash"),
+        480 => Some("http"),
+        481 => Some("http-path"),
+        443 => Some("https"),
+        477 => Some("ws"),
+        478 => Some("wss"),
+        479 => Some("p2p-websocket-star"),
+        277 => Some("p2p-stardust"),
+        275 => Some("p2p-webrtc-star"),
+        276 => Some("p2p-webrtc-direct"),
+        280 => Some("webrtc-direct"),
+        281 => Some("webrtc"),
+        290 => Some("p2p-circuit"),
+        777 => Some("memory"),
+        7367777 => Some("plaintextv2"),
+        _ => None,
     }
-    .to_string()
 }
 
-pub fn to_code(val: &str) -> u64 {
+pub fn try_to_code(val: &str) -> Option<u64> {
     match val {
-        "ip4" => 4,
-        "tcp" => 6,
-        "udp" => 273,
-        "dccp" => 33,
-        "ip6" => 41,
-        "ip6zone" => 42,
-        "ipcidr" => 43,
-        "dns" => 53,
-        "dns4" => 54,
-        "dns6" => 55,
-        "dnsaddr" => 56,
-        "sctp" => 132,
-        "udt" => 301,
-        "utp" => 302,
-        "unix" => 400,
-        "p2p" => 421,
-        "ipfs" => 421,
-        "onion" => 444,
-        "onion3" => 445,
-        "garlic64" => 446,
-        "garlic32" => 447,
-        "tls" => 448,
-        "sni" => 449,
-        "noise" => 454,
-        "quic" => 460,
-        "quic-v1" => 461,
-        "webtransport" => 465,
-        "certhash" => 466,
-        "http" => 480,
-        "https" => 443,
-        "ws" => 477,
-        "wss" => 478,
-        "p2p-websocket-star" => 479,
-        "p2p-stardust" => 277,
-        "p2p-webrtc-star" => 275,
-        "p2p-webrtc-direct" => 276,
-        "webrtc" => 280,
-        "p2p-circuit" => 290,
-        "memory" => 777,
-        _ => unreachable!(),
+        "ip4" => Some(4),
+        "tcp" => Some(6),
+        "udp" => Some(273),
+        "dccp" => Some(33),
+        "ip6" => Some(41),
+        "ip6zone" => Some(42),
+        "ipcidr" => Some(43),
+        "dns" => Some(53),
+        "dns4" => Some(54),
+        "dns6" => Some(55),
+        "dnsaddr" => Some(56),
+        "sctp" => Some(132),
+        "udt" => Some(301),
+        "utp" => Some(302),
+        "unix" => Some(400),
+        "p2p" => Some(421),
+        "ipfs" => Some(421),
+        "onion" => Some(444),
+        "onion3" => Some(445),
+        "garlic64" => Some(446),
+        "garlic32" => Some(447),
+        "tls" => Some(448),
+        "sni" => Some(449),
+        "noise" => Some(454),
+        "quic" => Some(460),
+        "quic-v1" => Some(461),
+        "webtransport" => Some(465),
+        "certhash" => Some(466),
+        "http" => Some(480),
+        "http-path" => Some(481),
+        "https" => Some(443),
+        "ws" => Some(477),
+        "wss" => Some(478),
+        "p2p-websocket-star" => Some(479),
+        "p2p-stardust" => Some(277),
+        "p2p-webrtc-star" => Some(275),
+        "p2p-webrtc-direct" => Some(276),
+        "webrtc-direct" => Some(280),
+        "webrtc" => Some(281),
+        "p2p-circuit" => Some(290),
+        "memory" => Some(777),
+        "plaintextv2" => Some(7367777),
+        _ => None,
     }
 }
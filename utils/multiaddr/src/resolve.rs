@@ -0,0 +1,413 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs, io,
+    net::{IpAddr, SocketAddr, ToSocketAddrs},
+    str::FromStr,
+};
+
+use crate::{dns, MultiAddr, Protocol, ResolveError};
+
+const DNSADDR_PREFIX: &str = "dnsaddr=";
+
+/// Default number of nested dnsaddr lookups followed before giving up.
+pub const DEFAULT_MAX_DEPTH: usize = 8;
+/// Default number of name lookups made for one resolution.
+pub const DEFAULT_MAX_LOOKUPS: usize = 32;
+/// Default number of addresses one resolution returns at most.
+pub const DEFAULT_MAX_ADDRS: usize = 100;
+
+/// Name lookups used to resolve multiaddrs.
+///
+/// A name without records resolves to an empty list, errors are kept for
+/// failures of the lookup itself.
+pub trait Resolver {
+    fn lookup_ip(&self, host: &str) -> io::Result<Vec<IpAddr>>;
+    fn lookup_txt(&self, name: &str) -> io::Result<Vec<String>>;
+}
+
+/// Resolves through the operating system.
+///
+/// Addresses go through the system lookup, so `/etc/hosts` is honoured, txt
+/// records are queried directly from a nameserver.
+#[derive(Debug, Clone)]
+pub struct SystemResolver {
+    nameserver: SocketAddr,
+}
+
+fn parse_resolv_conf(conf: &str) -> Option<IpAddr> {
+    conf.lines()
+        .filter_map(|line| line.trim().strip_prefix("nameserver"))
+        .find_map(|ip| ip.trim().parse().ok())
+}
+
+impl SystemResolver {
+    /// Uses the first nameserver of `/etc/resolv.conf`.
+    pub fn new() -> io::Result<Self> {
+        let conf = fs::read_to_string("/etc/resolv.conf")?;
+        let ip = parse_resolv_conf(&conf).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "no nameserver in /etc/resolv.conf")
+        })?;
+
+        Ok(Self::with_nameserver(SocketAddr::new(ip, 53)))
+    }
+
+    pub fn with_nameserver(nameserver: SocketAddr) -> Self {
+        Self { nameserver }
+    }
+}
+
+impl Resolver for SystemResolver {
+    fn lookup_ip(&self, host: &str) -> io::Result<Vec<IpAddr>> {
+        // the system lists an address once per socket type
+        let mut seen = HashSet::new();
+        let ips = (host, 0)
+            .to_socket_addrs()?
+            .map(|s| s.ip())
+            .filter(|ip| seen.insert(*ip))
+            .collect();
+
+        Ok(ips)
+    }
+
+    fn lookup_txt(&self, name: &str) -> io::Result<Vec<String>> {
+        dns::query_txt(self.nameserver, name)
+    }
+}
+
+/// A fixed set of records, for tests and static configurations.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryZone {
+    ips: HashMap<String, Vec<IpAddr>>,
+    txts: HashMap<String, Vec<String>>,
+}
+
+impl MemoryZone {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_ip(&mut self, host: &str, ip: IpAddr) {
+        self.ips.entry(host.to_string()).or_default().push(ip);
+    }
+
+    pub fn add_txt(&mut self, name: &str, txt: &str) {
+        self.txts
+            .entry(name.to_string())
+            .or_default()
+            .push(txt.to_string());
+    }
+}
+
+impl Resolver for MemoryZone {
+    fn lookup_ip(&self, host: &str) -> io::Result<Vec<IpAddr>> {
+        Ok(self.ips.get(host).cloned().unwrap_or_default())
+    }
+
+    fn lookup_txt(&self, name: &str) -> io::Result<Vec<String>> {
+        Ok(self.txts.get(name).cloned().unwrap_or_default())
+    }
+}
+
+/// Expands the dns components at the start of multiaddrs.
+///
+/// `/dns`, `/dns4` and `/dns6` become one address per matching ip, and
+/// `/dnsaddr` is replaced by the addresses in the `_dnsaddr` txt records of
+/// the name, which are resolved again. When a dnsaddr is followed by more
+/// protocols, typically `/p2p/<id>`, only the records ending with them are
+/// kept.
+///
+/// Records can point anywhere, so a resolution is bounded: dnsaddr records
+/// nested deeper than `max_depth` are skipped, as are the names left once
+/// `max_lookups` lookups were made, and at most `max_addrs` addresses are
+/// returned.
+#[derive(Debug, Clone)]
+pub struct DnsResolver<R> {
+    resolver: R,
+    pub max_depth: usize,
+    pub max_lookups: usize,
+    pub max_addrs: usize,
+}
+
+impl<R: Resolver> DnsResolver<R> {
+    pub fn new(resolver: R) -> Self {
+        Self {
+            resolver,
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_lookups: DEFAULT_MAX_LOOKUPS,
+            max_addrs: DEFAULT_MAX_ADDRS,
+        }
+    }
+
+    /// Resolves `addr`, addresses without dns components are returned as is.
+    pub fn resolve(&self, addr: &MultiAddr) -> Result<Vec<MultiAddr>, ResolveError> {
+        let mut lookups = 0;
+        let mut addrs = self.resolve_depth(addr, 0, &mut lookups)?;
+        addrs.truncate(self.max_addrs);
+
+        Ok(addrs)
+    }
+
+    // counts a lookup against the budget, false once it is spent
+    fn take_lookup(&self, lookups: &mut usize) -> bool {
+        *lookups += 1;
+        *lookups <= self.max_lookups
+    }
+
+    fn resolve_depth(
+        &self,
+        addr: &MultiAddr,
+        depth: usize,
+        lookups: &mut usize,
+    ) -> Result<Vec<MultiAddr>, ResolveError> {
+        let mut iter = addr.iter();
+        let first = match iter.next() {
+            Some(first) => first,
+            None => return Ok(vec![addr.clone()]),
+        };
+
+        let mut rest = MultiAddr::new();
+        for protocol in iter {
+            rest.push_unchecked(protocol);
+        }
+
+        let (host, v4, v6) = match &first {
+            Protocol::DNS(host) => (host, true, true),
+            Protocol::DNS4(host) => (host, true, false),
+            Protocol::DNS6(host) => (host, false, true),
+            Protocol::DNSAddr(host) => return self.resolve_dnsaddr(host, &rest, depth, lookups),
+            _ => return Ok(vec![addr.clone()]),
+        };
+        if !self.take_lookup(lookups) {
+            return Ok(Vec::new());
+        }
+
+        let ips = self.resolver.lookup_ip(host)?;
+        let addrs = ips
+            .into_iter()
+            .filter(|ip| if ip.is_ipv4() { v4 } else { v6 })
+            .map(|ip| MultiAddr::from(ip).encapsulate(&rest))
+            .collect();
+
+        Ok(addrs)
+    }
+
+    fn resolve_dnsaddr(
+        &self,
+        host: &str,
+        rest: &MultiAddr,
+        depth: usize,
+        lookups: &mut usize,
+    ) -> Result<Vec<MultiAddr>, ResolveError> {
+        if depth >= self.max_depth || !self.take_lookup(lookups) {
+            return Ok(Vec::new());
+        }
+
+        let mut addrs = Vec::new();
+        for txt in self.resolver.lookup_txt(&format!("_dnsaddr.{}", host))? {
+            // records we don't understand are skipped, not fatal
+            let found = match txt.strip_prefix(DNSADDR_PREFIX).map(MultiAddr::from_str) {
+                Some(Ok(found)) => found,
+                _ => continue,
+            };
+            if !found.ends_with(rest) {
+                continue;
+            }
+
+            addrs.extend(self.resolve_depth(&found, depth + 1, lookups)?);
+            if addrs.len() >= self.max_addrs {
+                break;
+            }
+        }
+
+        Ok(addrs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PEER_A: &str = "QmNnooDu7bfjPFoTZYxMNLWUQJyrVwtbZg5gBMjTezGAJN";
+    const PEER_B: &str = "QmQCU2EcMqAqQPR2i9bChDtGNJchTbq5TbXJJ16u19uLTa";
+
+    fn addr(s: &str) -> MultiAddr {
+        MultiAddr::from_str(s).unwrap()
+    }
+
+    fn addrs(list: &[&str]) -> Vec<MultiAddr> {
+        list.iter().map(|s| addr(s)).collect()
+    }
+
+    fn zone() -> MemoryZone {
+        let mut zone = MemoryZone::new();
+
+        zone.add_ip("example.com", "192.0.2.1".parse().unwrap());
+        zone.add_ip("example.com", "2001:db8::1".parse().unwrap());
+
+        zone.add_txt(
+            "_dnsaddr.bootstrap.example.com",
+            &format!("dnsaddr=/dnsaddr/a.bootstrap.example.com/p2p/{}", PEER_A),
+        );
+        zone.add_txt(
+            "_dnsaddr.bootstrap.example.com",
+            &format!("dnsaddr=/dnsaddr/b.bootstrap.example.com/p2p/{}", PEER_B),
+        );
+        zone.add_txt("_dnsaddr.bootstrap.example.com", "v=spf1 -all");
+        zone.add_txt("_dnsaddr.bootstrap.example.com", "dnsaddr=/not/an/addr");
+
+        zone.add_txt(
+            "_dnsaddr.a.bootstrap.example.com",
+            &format!("dnsaddr=/ip4/192.0.2.10/tcp/4001/p2p/{}", PEER_A),
+        );
+        zone.add_txt(
+            "_dnsaddr.a.bootstrap.example.com",
+            &format!("dnsaddr=/dns6/example.com/udp/4001/quic-v1/p2p/{}", PEER_A),
+        );
+        zone.add_txt(
+            "_dnsaddr.b.bootstrap.example.com",
+            &format!("dnsaddr=/ip4/192.0.2.20/tcp/4001/p2p/{}", PEER_B),
+        );
+
+        zone.add_txt(
+            "_dnsaddr.loop.example.com",
+            "dnsaddr=/dnsaddr/loop.example.com",
+        );
+        zone.add_txt(
+            "_dnsaddr.mixed.example.com",
+            "dnsaddr=/dnsaddr/loop.example.com",
+        );
+        zone.add_txt(
+            "_dnsaddr.mixed.example.com",
+            "dnsaddr=/ip4/192.0.2.30/tcp/4001",
+        );
+
+        zone
+    }
+
+    #[test]
+    fn test_resolve_dns() {
+        let resolver = DnsResolver::new(zone());
+
+        assert_eq!(
+            resolver.resolve(&addr("/dns/example.com/tcp/443")).unwrap(),
+            addrs(&["/ip4/192.0.2.1/tcp/443", "/ip6/2001:db8::1/tcp/443"])
+        );
+        assert_eq!(
+            resolver
+                .resolve(&addr("/dns4/example.com/tcp/443/wss"))
+                .unwrap(),
+            addrs(&["/ip4/192.0.2.1/tcp/443/wss"])
+        );
+        assert_eq!(
+            resolver.resolve(&addr("/dns6/example.com")).unwrap(),
+            addrs(&["/ip6/2001:db8::1"])
+        );
+        assert!(resolver
+            .resolve(&addr("/dns4/missing.example.com/tcp/1"))
+            .unwrap()
+            .is_empty());
+
+        let plain = addr("/ip4/127.0.0.1/tcp/1/dns4/example.com");
+        assert_eq!(resolver.resolve(&plain).unwrap(), vec![plain]);
+    }
+
+    #[test]
+    fn test_resolve_dnsaddr() {
+        let resolver = DnsResolver::new(zone());
+
+        let all = resolver
+            .resolve(&addr("/dnsaddr/bootstrap.example.com"))
+            .unwrap();
+        assert_eq!(
+            all,
+            addrs(&[
+                &format!("/ip4/192.0.2.10/tcp/4001/p2p/{}", PEER_A),
+                &format!("/ip6/2001:db8::1/udp/4001/quic-v1/p2p/{}", PEER_A),
+                &format!("/ip4/192.0.2.20/tcp/4001/p2p/{}", PEER_B),
+            ])
+        );
+
+        let only_b = resolver
+            .resolve(&addr(&format!(
+                "/dnsaddr/bootstrap.example.com/p2p/{}",
+                PEER_B
+            )))
+            .unwrap();
+        assert_eq!(
+            only_b,
+            addrs(&[&format!("/ip4/192.0.2.20/tcp/4001/p2p/{}", PEER_B)])
+        );
+    }
+
+    #[test]
+    fn test_resolve_depth() {
+        let mut resolver = DnsResolver::new(zone());
+
+        assert!(resolver
+            .resolve(&addr("/dnsaddr/loop.example.com"))
+            .unwrap()
+            .is_empty());
+
+        // the loop is skipped, not the records next to it
+        assert_eq!(
+            resolver
+                .resolve(&addr("/dnsaddr/mixed.example.com"))
+                .unwrap(),
+            addrs(&["/ip4/192.0.2.30/tcp/4001"])
+        );
+
+        // bootstrap -> a/b -> ips needs two dnsaddr lookups
+        resolver.max_depth = 1;
+        assert!(resolver
+            .resolve(&addr("/dnsaddr/bootstrap.example.com"))
+            .unwrap()
+            .is_empty());
+        resolver.max_depth = 2;
+        assert_eq!(
+            resolver
+                .resolve(&addr("/dnsaddr/bootstrap.example.com"))
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_resolve_limits() {
+        let mut resolver = DnsResolver::new(zone());
+
+        resolver.max_addrs = 2;
+        assert_eq!(
+            resolver
+                .resolve(&addr("/dnsaddr/bootstrap.example.com"))
+                .unwrap(),
+            addrs(&[
+                &format!("/ip4/192.0.2.10/tcp/4001/p2p/{}", PEER_A),
+                &format!("/ip6/2001:db8::1/udp/4001/quic-v1/p2p/{}", PEER_A),
+            ])
+        );
+
+        // bootstrap and a, leaving nothing for the dns6 name and b
+        resolver.max_addrs = DEFAULT_MAX_ADDRS;
+        resolver.max_lookups = 2;
+        assert_eq!(
+            resolver
+                .resolve(&addr("/dnsaddr/bootstrap.example.com"))
+                .unwrap(),
+            addrs(&[&format!("/ip4/192.0.2.10/tcp/4001/p2p/{}", PEER_A)])
+        );
+
+        resolver.max_lookups = 0;
+        assert!(resolver
+            .resolve(&addr("/dns/example.com/tcp/443"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_resolv_conf() {
+        let conf = "# generated\nsearch lan\nnameserver fe80::1%eth0\nnameserver 10.0.0.1\n";
+        assert_eq!(parse_resolv_conf(conf), Some("10.0.0.1".parse().unwrap()));
+        assert_eq!(parse_resolv_conf("search lan\n"), None);
+    }
+}
@@ -0,0 +1,276 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::{MultiAddr, Pattern, Protocol};
+
+/// Where an address can be reached from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// This host only, including `/unix` and `/memory`.
+    Loopback,
+    /// The local link, 169.254.0.0/16 and fe80::/10.
+    LinkLocal,
+    /// RFC 1918 networks and unique local ipv6, fc00::/7.
+    Private,
+    /// Carrier grade nat, 100.64.0.0/10.
+    Shared,
+    /// ipv6 translated to ipv4, 64:ff9b::/96 and 64:ff9b:1::/48.
+    NAT64,
+    /// Globally routable.
+    Public,
+    /// Unspecified, multicast, documentation and other special purpose
+    /// ranges that can't be dialed.
+    Reserved,
+    /// The address doesn't start with a network address.
+    Unknown,
+}
+
+fn ipv4_scope(ip: Ipv4Addr) -> Scope {
+    let [a, b, c, _] = ip.octets();
+
+    match (a, b, c) {
+        (127, ..) => Scope::Loopback,
+        (169, 254, _) => Scope::LinkLocal,
+        (10, ..) | (192, 168, _) => Scope::Private,
+        (172, b, _) if b & 0xf0 == 16 => Scope::Private,
+        (100, b, _) if b & 0xc0 == 64 => Scope::Shared,
+        (0, ..) | (192, 0, 0) | (192, 0, 2) | (198, 51, 100) | (203, 0, 113) => Scope::Reserved,
+        (198, b, _) if b & 0xfe == 18 => Scope::Reserved,
+        // multicast, the reserved class e and broadcast
+        (a, ..) if a >= 224 => Scope::Reserved,
+        _ => Scope::Public,
+    }
+}
+
+fn ipv6_scope(ip: Ipv6Addr) -> Scope {
+    if let Some(ip) = ip.to_ipv4_mapped() {
+        return ipv4_scope(ip);
+    }
+
+    let seg = ip.segments();
+    match seg {
+        [0, 0, 0, 0, 0, 0, 0, 1] => Scope::Loopback,
+        [0x64, 0xff9b, 0, 0, 0, 0, ..] | [0x64, 0xff9b, 1, ..] => Scope::NAT64,
+        [0x2001, 0xdb8, ..] => Scope::Reserved,
+        [s, ..] if s & 0xffc0 == 0xfe80 => Scope::LinkLocal,
+        [s, ..] if s & 0xfe00 == 0xfc00 => Scope::Private,
+        // global unicast, everything else is unassigned or special purpose
+        [s, ..] if s & 0xe000 == 0x2000 => Scope::Public,
+        _ => Scope::Reserved,
+    }
+}
+
+fn dns_scope(host: &str) -> Scope {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+
+    if host == "localhost" || host.ends_with(".localhost") {
+        Scope::Loopback
+    } else if host.ends_with(".local") {
+        Scope::LinkLocal
+    } else {
+        Scope::Public
+    }
+}
+
+impl MultiAddr {
+    /// Classifies the address by its first protocol, for a relayed address
+    /// this is the scope of the relay. A leading `/ip6zone` is skipped.
+    ///
+    /// Dns names are public unless they are `localhost` or mdns `.local`
+    /// names, since where they point isn't known before resolving.
+    pub fn scope(&self) -> Scope {
+        let mut iter = self.iter();
+        let mut first = iter.next();
+        if let Some(Protocol::IP6Zone(_)) = first {
+            first = iter.next();
+        }
+
+        match first {
+            Some(Protocol::IPv4(ip)) => ipv4_scope(ip),
+            Some(Protocol::IPv6(ip)) => ipv6_scope(ip),
+            Some(
+                Protocol::DNS(host)
+                | Protocol::DNS4(host)
+                | Protocol::DNS6(host)
+                | Protocol::DNSAddr(host),
+            ) => dns_scope(&host),
+            Some(Protocol::Unix(_) | Protocol::Memory(_)) => Scope::Loopback,
+            _ => Scope::Unknown,
+        }
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.scope() == Scope::Public
+    }
+
+    /// Whether the address is in an RFC 1918 or unique local range, shared
+    /// carrier grade nat addresses are not private.
+    pub fn is_private(&self) -> bool {
+        self.scope() == Scope::Private
+    }
+
+    pub fn is_loopback(&self) -> bool {
+        self.scope() == Scope::Loopback
+    }
+
+    /// Whether the address goes through a circuit relay.
+    pub fn is_relayed(&self) -> bool {
+        self.iter().any(|protocol| protocol == Protocol::P2PCircuit)
+    }
+}
+
+fn scope_rank(scope: Scope) -> u8 {
+    match scope {
+        Scope::Loopback => 0,
+        Scope::Private => 1,
+        Scope::LinkLocal => 2,
+        Scope::Shared => 3,
+        Scope::Public => 4,
+        Scope::NAT64 => 5,
+        Scope::Unknown => 6,
+        Scope::Reserved => 7,
+    }
+}
+
+fn transport_rank(addr: &MultiAddr) -> u8 {
+    let mut addr = addr.clone();
+    if let Some(Protocol::P2P(_)) = addr.iter().last() {
+        addr.pop();
+    }
+
+    let ranked = [
+        Pattern::quic_v1(),
+        Pattern::tcp(),
+        Pattern::webtransport(),
+        Pattern::websocket(),
+        Pattern::webrtc_direct(),
+        Pattern::quic(),
+    ];
+    ranked
+        .iter()
+        .position(|pattern| pattern.matches(&addr))
+        .unwrap_or(ranked.len()) as u8
+}
+
+/// Sorts addresses from the most to the least preferred for dialing.
+///
+/// Direct addresses come before relayed ones, then closer scopes before
+/// farther ones, then quic-v1, tcp, webtransport, websocket, webrtc-direct
+/// and draft quic. Addresses ranked the same keep their order.
+pub fn sort_addrs(addrs: &mut [MultiAddr]) {
+    addrs.sort_by_cached_key(|addr| {
+        (
+            addr.is_relayed(),
+            scope_rank(addr.scope()),
+            transport_rank(addr),
+        )
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn addr(s: &str) -> MultiAddr {
+        MultiAddr::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_scope() {
+        for (s, scope) in [
+            ("/ip4/127.0.0.1/tcp/1", Scope::Loopback),
+            ("/ip6/::1", Scope::Loopback),
+            ("/unix/%2Ftmp%2Fsock", Scope::Loopback),
+            ("/memory/1", Scope::Loopback),
+            ("/dns/localhost/tcp/1", Scope::Loopback),
+            ("/dns4/node.localhost", Scope::Loopback),
+            ("/ip4/169.254.1.1", Scope::LinkLocal),
+            ("/ip6/fe80::1", Scope::LinkLocal),
+            ("/ip6zone/eth0/ip6/fe80::1/tcp/1", Scope::LinkLocal),
+            ("/dns/printer.local", Scope::LinkLocal),
+            ("/ip4/10.1.2.3", Scope::Private),
+            ("/ip4/172.16.0.1", Scope::Private),
+            ("/ip4/172.31.255.255", Scope::Private),
+            ("/ip4/192.168.1.1", Scope::Private),
+            ("/ip6/fd00::1", Scope::Private),
+            ("/ip6/::ffff:192.168.1.1", Scope::Private),
+            ("/ip4/100.64.0.1", Scope::Shared),
+            ("/ip4/100.127.255.255", Scope::Shared),
+            ("/ip6/64:ff9b::102:304", Scope::NAT64),
+            ("/ip6/64:ff9b:1::1", Scope::NAT64),
+            ("/ip4/1.1.1.1", Scope::Public),
+            ("/ip4/172.32.0.1", Scope::Public),
+            ("/ip4/100.128.0.1", Scope::Public),
+            ("/ip6/2606:4700::1111", Scope::Public),
+            ("/ip6/::ffff:8.8.8.8", Scope::Public),
+            ("/dns4/example.com/tcp/443", Scope::Public),
+            ("/ip4/0.0.0.0", Scope::Reserved),
+            ("/ip4/192.0.2.1", Scope::Reserved),
+            ("/ip4/198.18.0.1", Scope::Reserved),
+            ("/ip4/224.0.0.1", Scope::Reserved),
+            ("/ip4/255.255.255.255", Scope::Reserved),
+            ("/ip6/::", Scope::Reserved),
+            ("/ip6/2001:db8::1", Scope::Reserved),
+            ("/ip6/ff02::1", Scope::Reserved),
+            ("/p2p-circuit", Scope::Unknown),
+            ("/ip6zone/eth0", Scope::Unknown),
+        ] {
+            assert_eq!(addr(s).scope(), scope, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_classification() {
+        let public = addr("/ip4/1.1.1.1/tcp/4001");
+        assert!(public.is_public() && !public.is_private() && !public.is_loopback());
+
+        assert!(addr("/ip4/192.168.0.1/tcp/4001").is_private());
+        assert!(!addr("/ip4/100.64.0.1/tcp/4001").is_private());
+        assert!(addr("/ip6/::1/udp/1/quic-v1").is_loopback());
+
+        let relayed = addr(
+            "/ip4/1.1.1.1/tcp/4001/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC/p2p-circuit",
+        );
+        assert!(relayed.is_relayed());
+        assert!(relayed.is_public());
+        assert!(!public.is_relayed());
+    }
+
+    #[test]
+    fn test_sort_addrs() {
+        let relay =
+            "/ip4/1.1.1.1/tcp/4001/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC/p2p-circuit";
+
+        let mut addrs = vec![
+            addr(relay),
+            addr("/ip4/8.8.8.8/tcp/4001/ws"),
+            addr("/ip4/8.8.8.8/tcp/4001"),
+            addr("/ip4/192.0.2.1/tcp/4001"),
+            addr("/dns4/example.com/tcp/4001"),
+            addr(
+                "/ip4/8.8.8.8/udp/4001/quic-v1/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC",
+            ),
+            addr("/ip4/192.168.1.1/tcp/4001"),
+            addr("/ip4/127.0.0.1/tcp/4001"),
+            addr("/ip6/2606:4700::1111/tcp/4001"),
+        ];
+        sort_addrs(&mut addrs);
+
+        let sorted: Vec<String> = addrs.iter().map(|a| a.to_string()).collect();
+        assert_eq!(
+            sorted,
+            [
+                "/ip4/127.0.0.1/tcp/4001",
+                "/ip4/192.168.1.1/tcp/4001",
+                "/ip4/8.8.8.8/udp/4001/quic-v1/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC",
+                "/ip4/8.8.8.8/tcp/4001",
+                "/dns4/example.com/tcp/4001",
+                "/ip6/2606:4700::1111/tcp/4001",
+                "/ip4/8.8.8.8/tcp/4001/ws",
+                "/ip4/192.0.2.1/tcp/4001",
+                relay,
+            ]
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{MultiAddr, MultiAddrSlice};
+
+// string form for human readable formats, bytes otherwise
+impl Serialize for MultiAddr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+impl Serialize for MultiAddrSlice<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+struct MultiAddrVisitor;
+
+impl de::Visitor<'_> for MultiAddrVisitor {
+    type Value = MultiAddr;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a multiaddr")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        // an empty multiaddr displays as nothing, which from_str rejects
+        if v.is_empty() {
+            return Ok(MultiAddr::new());
+        }
+
+        MultiAddr::from_str(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        MultiAddr::from_bytes(v).map_err(E::custom)
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        MultiAddr::try_from(v).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for MultiAddr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(MultiAddrVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(MultiAddrVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRS: [&str; 3] = [
+        "/ip4/127.0.0.1/tcp/4001/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC",
+        "/dns4/example.com/udp/443/quic-v1/webtransport/certhash/uEiDDq4_xNyDorZBH3TlGazyJdOWSwvo4PUo5YHFMrvDE8g",
+        "",
+    ];
+
+    fn addrs() -> Vec<MultiAddr> {
+        ADDRS
+            .iter()
+            .map(|s| match s {
+                &"" => MultiAddr::new(),
+                s => MultiAddr::from_str(s).unwrap(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_json() {
+        let addrs = addrs();
+
+        let json = serde_json::to_string(&addrs).unwrap();
+        assert_eq!(json, format!("[\"{}\",\"{}\",\"\"]", ADDRS[0], ADDRS[1]));
+        assert_eq!(
+            serde_json::from_str::<Vec<MultiAddr>>(&json).unwrap(),
+            addrs
+        );
+
+        assert_eq!(
+            serde_json::to_string(&addrs[0].as_slice()).unwrap(),
+            format!("\"{}\"", ADDRS[0])
+        );
+        assert!(serde_json::from_str::<MultiAddr>("\"/ip4/1.2.3.4/tcp\"").is_err());
+        assert!(serde_json::from_str::<MultiAddr>("4001").is_err());
+    }
+
+    #[test]
+    fn test_binary() {
+        let addrs = addrs();
+
+        let bytes = bincode::serialize(&addrs).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Vec<MultiAddr>>(&bytes).unwrap(),
+            addrs
+        );
+
+        // a length followed by the raw multiaddr bytes
+        let bytes = bincode::serialize(&addrs[0]).unwrap();
+        assert_eq!(bytes[..8], (addrs[0].len() as u64).to_le_bytes());
+        assert_eq!(&bytes[8..], addrs[0].as_bytes());
+
+        let mut truncated = bincode::serialize(&vec![0x04u8, 0x7f]).unwrap();
+        assert!(bincode::deserialize::<MultiAddr>(&truncated).is_err());
+        truncated.truncate(9);
+        assert!(bincode::deserialize::<MultiAddr>(&truncated).is_err());
+    }
+}
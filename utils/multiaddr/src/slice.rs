@@ -0,0 +1,193 @@
+use std::fmt;
+
+use crate::{Error, MultiAddr, Protocol, ProtocolIter};
+
+/// A borrowed multiaddr, what `&str` is to `String`.
+///
+/// The bytes are checked once when the slice is made, so addresses inside a
+/// larger buffer can be inspected without copying them out.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MultiAddrSlice<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> MultiAddrSlice<'a> {
+    /// Borrows a binary multiaddr, checking every protocol in it.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, Error> {
+        let mut slice = bytes;
+        while !slice.is_empty() {
+            let (_, len) = Protocol::from_bytes(slice)?;
+            slice = &slice[len..];
+        }
+
+        Ok(Self { bytes })
+    }
+
+    // the caller guarantees the bytes hold whole valid protocols
+    pub(crate) fn new_unchecked(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    pub fn iter(&self) -> ProtocolIter<'a> {
+        ProtocolIter(self.bytes)
+    }
+
+    pub fn to_multiaddr(&self) -> MultiAddr {
+        MultiAddr::from(*self)
+    }
+
+    // byte offsets where each protocol starts, followed by the end
+    pub(crate) fn boundaries(&self) -> Vec<usize> {
+        let mut boundaries = vec![0];
+
+        let mut slice = self.bytes;
+        while let Ok((_, len)) = Protocol::from_bytes(slice) {
+            slice = &slice[len..];
+            boundaries.push(self.bytes.len() - slice.len());
+        }
+
+        boundaries
+    }
+
+    /// Splits off the first protocol, borrowing both halves.
+    pub fn split_first(&self) -> Option<(Protocol<'a>, MultiAddrSlice<'a>)> {
+        let (protocol, len) = Protocol::from_bytes(self.bytes).ok()?;
+
+        Some((protocol, Self::new_unchecked(&self.bytes[len..])))
+    }
+
+    pub fn starts_with(&self, other: MultiAddrSlice<'_>) -> bool {
+        // protocols are self-delimiting, so a matching byte prefix always
+        // ends on a protocol boundary
+        self.bytes.starts_with(other.bytes)
+    }
+
+    pub fn ends_with(&self, other: MultiAddrSlice<'_>) -> bool {
+        self.bytes.ends_with(other.bytes)
+            && self
+                .boundaries()
+                .contains(&(self.bytes.len() - other.bytes.len()))
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for MultiAddrSlice<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::new(bytes)
+    }
+}
+
+impl<'a> IntoIterator for MultiAddrSlice<'a> {
+    type Item = Protocol<'a>;
+    type IntoIter = ProtocolIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl PartialEq<MultiAddr> for MultiAddrSlice<'_> {
+    fn eq(&self, other: &MultiAddr) -> bool {
+        *self == other.as_slice()
+    }
+}
+
+impl PartialEq<MultiAddrSlice<'_>> for MultiAddr {
+    fn eq(&self, other: &MultiAddrSlice<'_>) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl fmt::Debug for MultiAddrSlice<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MultiAddrSlice")
+            .field(&self.to_string())
+            .finish()
+    }
+}
+
+impl fmt::Display for MultiAddrSlice<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for protocol in self.iter() {
+            protocol.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, str::FromStr};
+
+    use super::*;
+
+    const FULL: &str = "/ip4/127.0.0.1/udp/1234/quic-v1/webtransport/certhash/uEiDDq4_xNyDorZBH3TlGazyJdOWSwvo4PUo5YHFMrvDE8g/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC";
+
+    #[test]
+    fn test_borrowed() {
+        let owned = MultiAddr::from_str(FULL).unwrap();
+
+        // as if it came out of a protobuf message
+        let mut buf = vec![0x12, owned.len() as u8];
+        buf.extend_from_slice(owned.as_bytes());
+
+        let slice = MultiAddrSlice::new(&buf[2..]).unwrap();
+        assert_eq!(slice, owned);
+        assert_eq!(owned, slice);
+        assert_eq!(slice.to_string(), FULL);
+        assert_eq!(slice.to_multiaddr(), owned);
+
+        // byte values point into the buffer instead of being copied
+        for protocol in slice {
+            match protocol {
+                Protocol::CertHash(Cow::Borrowed(hash)) | Protocol::P2P(Cow::Borrowed(hash)) => {
+                    let range = buf.as_ptr_range();
+                    assert!(range.contains(&hash.as_ptr()));
+                }
+                Protocol::CertHash(_) | Protocol::P2P(_) => panic!("{} was copied", protocol),
+                _ => {}
+            }
+        }
+
+        assert!(MultiAddrSlice::new(&buf).is_err());
+        assert!(MultiAddrSlice::try_from(&buf[2..buf.len() - 1]).is_err());
+        assert!(MultiAddrSlice::new(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_split_first() {
+        let owned = MultiAddr::from_str("/ip4/127.0.0.1/tcp/4001/ws").unwrap();
+        let slice = owned.as_slice();
+
+        let (first, rest) = slice.split_first().unwrap();
+        assert_eq!(first, Protocol::IPv4([127, 0, 0, 1].into()));
+        assert_eq!(rest.to_string(), "/tcp/4001/ws");
+        assert!(slice.ends_with(rest));
+        assert!(!rest.starts_with(slice));
+
+        let (_, rest) = rest.split_first().unwrap();
+        let (last, rest) = rest.split_first().unwrap();
+        assert_eq!(last, Protocol::WS);
+        assert!(rest.is_empty());
+        assert_eq!(rest.split_first(), None);
+
+        assert_eq!(
+            format!("{:?}", slice),
+            "MultiAddrSlice(\"/ip4/127.0.0.1/tcp/4001/ws\")"
+        );
+    }
+}
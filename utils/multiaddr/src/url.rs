@@ -0,0 +1,340 @@
+use std::{
+    borrow::Cow,
+    net::{Ipv4Addr, Ipv6Addr},
+};
+
+use crate::{protocol::percent_decode, MultiAddr, Protocol, UrlError};
+
+fn unsupported(protocol: Option<Protocol<'_>>) -> UrlError {
+    match protocol {
+        Some(protocol) => UrlError::Unsupported(protocol.name()),
+        None => UrlError::Incomplete,
+    }
+}
+
+fn default_port(scheme: &str) -> u16 {
+    match scheme {
+        "https" | "wss" => 443,
+        _ => 80,
+    }
+}
+
+// keeps the characters allowed in a url path as is
+fn encode_path(path: &str) -> String {
+    let mut output = String::with_capacity(path.len());
+    for b in path.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => output.push(b as char),
+            b'-' | b'.' | b'_' | b'~' | b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+'
+            | b',' | b';' | b'=' | b':' | b'@' | b'/' => output.push(b as char),
+            _ => output.push_str(&format!("%{:02X}", b)),
+        }
+    }
+
+    output
+}
+
+// a zone id may only hold unreserved characters, the rest is escaped as
+// rfc 6874 requires, starting with the % that would end it
+fn encode_zone(zone: &str) -> String {
+    let mut output = String::with_capacity(zone.len());
+    for b in zone.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                output.push(b as char)
+            }
+            _ => output.push_str(&format!("%{:02X}", b)),
+        }
+    }
+
+    output
+}
+
+impl MultiAddr {
+    /// Converts `<host>/tcp/<port>` followed by `/http`, `/https`,
+    /// `/tls/http`, `/ws`, `/wss` or `/tls/ws` and an optional `/http-path`
+    /// to a url.
+    ///
+    /// Default ports are left out, and `/sni` is only accepted when it
+    /// repeats the host since a url can't carry both.
+    pub fn to_url(&self) -> Result<String, UrlError> {
+        let mut iter = self.iter();
+
+        let host = match iter.next() {
+            Some(Protocol::IPv4(ip)) => ip.to_string(),
+            Some(Protocol::IPv6(ip)) => format!("[{}]", ip),
+            Some(Protocol::IP6Zone(zone)) => match iter.next() {
+                Some(Protocol::IPv6(ip)) => format!("[{}%25{}]", ip, encode_zone(&zone)),
+                other => return Err(unsupported(other)),
+            },
+            Some(Protocol::DNS(host) | Protocol::DNS4(host) | Protocol::DNS6(host)) => {
+                host.into_owned()
+            }
+            other => return Err(unsupported(other)),
+        };
+        let port = match iter.next() {
+            Some(Protocol::TCP(port)) => port,
+            other => return Err(unsupported(other)),
+        };
+
+        let scheme = match iter.next() {
+            Some(Protocol::HTTP) => "http",
+            Some(Protocol::HTTPS) => "https",
+            Some(Protocol::WS) => "ws",
+            Some(Protocol::WSS) => "wss",
+            Some(Protocol::TLS) => {
+                let mut next = iter.next();
+                if let Some(Protocol::SNI(sni)) = &next {
+                    if *sni != host {
+                        return Err(UrlError::Unsupported("sni"));
+                    }
+                    next = iter.next();
+                }
+
+                match next {
+                    Some(Protocol::HTTP) => "https",
+                    Some(Protocol::WS) => "wss",
+                    other => return Err(unsupported(other)),
+                }
+            }
+            other => return Err(unsupported(other)),
+        };
+
+        let path = match iter.next() {
+            Some(Protocol::HTTPPath(path)) => format!("/{}", encode_path(&path)),
+            None => String::new(),
+            other => return Err(unsupported(other)),
+        };
+        if let Some(other) = iter.next() {
+            return Err(unsupported(Some(other)));
+        }
+
+        let port = match port == default_port(scheme) {
+            true => String::new(),
+            false => format!(":{}", port),
+        };
+
+        Ok(format!("{}://{}{}{}", scheme, host, port, path))
+    }
+
+    /// Parses an `http`, `https`, `ws` or `wss` url, the inverse of
+    /// [`MultiAddr::to_url`].
+    ///
+    /// Secure schemes become `/https` and `/wss`, names become `/dns`, and a
+    /// path other than `/` becomes `/http-path`. Urls with credentials, a
+    /// query or a fragment are rejected.
+    pub fn from_url(url: &str) -> Result<MultiAddr, UrlError> {
+        let (scheme, rest) = url
+            .split_once("://")
+            .ok_or(UrlError::InvalidUrl("scheme"))?;
+        let scheme = scheme.to_ascii_lowercase();
+        let last = match scheme.as_str() {
+            "http" => Protocol::HTTP,
+            "https" => Protocol::HTTPS,
+            "ws" => Protocol::WS,
+            "wss" => Protocol::WSS,
+            _ => return Err(UrlError::UnsupportedScheme(scheme)),
+        };
+
+        if rest.contains('?') {
+            return Err(UrlError::InvalidUrl("query"));
+        }
+        if rest.contains('#') {
+            return Err(UrlError::InvalidUrl("fragment"));
+        }
+
+        let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        if authority.contains('@') {
+            return Err(UrlError::InvalidUrl("userinfo"));
+        }
+
+        let mut addr = MultiAddr::new();
+
+        let port = match authority.strip_prefix('[') {
+            Some(bracketed) => {
+                let (host, port) = bracketed
+                    .split_once(']')
+                    .ok_or(UrlError::InvalidUrl("host"))?;
+                let (ip, zone) = match host.split_once("%25") {
+                    Some((ip, zone)) => (ip, Some(zone)),
+                    None => (host, None),
+                };
+                let ip: Ipv6Addr = ip.parse().map_err(|_| UrlError::InvalidUrl("host"))?;
+
+                if let Some(zone) = zone {
+                    let zone = percent_decode(zone).ok_or(UrlError::InvalidUrl("host"))?;
+                    addr.push(Protocol::IP6Zone(Cow::Owned(zone)))
+                        .map_err(|_| UrlError::InvalidUrl("host"))?;
+                }
+                addr.push_unchecked(Protocol::IPv6(ip));
+
+                match port {
+                    "" => None,
+                    port => Some(port.strip_prefix(':').ok_or(UrlError::InvalidUrl("host"))?),
+                }
+            }
+            None => {
+                let (host, port) = match authority.split_once(':') {
+                    Some((host, port)) => (host, Some(port)),
+                    None => (authority, None),
+                };
+                if host.is_empty() || host.contains('%') {
+                    return Err(UrlError::InvalidUrl("host"));
+                }
+
+                match host.parse::<Ipv4Addr>() {
+                    Ok(ip) => addr.push_unchecked(Protocol::IPv4(ip)),
+                    Err(_) => addr
+                        .push(Protocol::DNS(Cow::Borrowed(host)))
+                        .map_err(|_| UrlError::InvalidUrl("host"))?,
+                }
+
+                port
+            }
+        };
+
+        let port = match port {
+            Some(port) => match port.parse() {
+                Ok(0) | Err(_) => return Err(UrlError::InvalidUrl("port")),
+                Ok(port) => port,
+            },
+            None => default_port(&scheme),
+        };
+        addr.push_unchecked(Protocol::TCP(port));
+        addr.push_unchecked(last);
+
+        match path.strip_prefix('/') {
+            Some("") | None => {}
+            Some(path) => {
+                let path = percent_decode(path).ok_or(UrlError::InvalidUrl("path"))?;
+                addr.push_unchecked(Protocol::HTTPPath(Cow::Owned(path)));
+            }
+        }
+
+        Ok(addr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn addr(s: &str) -> MultiAddr {
+        MultiAddr::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for (s, url) in [
+            ("/dns/example.com/tcp/443/https", "https://example.com"),
+            ("/ip4/127.0.0.1/tcp/8080/http", "http://127.0.0.1:8080"),
+            ("/ip6/::1/tcp/80/ws", "ws://[::1]"),
+            ("/dns/example.com/tcp/8443/wss", "wss://example.com:8443"),
+            (
+                "/ip6zone/eth0/ip6/fe80::1/tcp/80/http",
+                "http://[fe80::1%25eth0]",
+            ),
+            (
+                "/ip6zone/eth%1/ip6/fe80::1/tcp/80/http",
+                "http://[fe80::1%25eth%251]",
+            ),
+            (
+                "/ip6zone/a b/ip6/fe80::1/tcp/80/http",
+                "http://[fe80::1%25a%20b]",
+            ),
+            (
+                "/dns/example.com/tcp/443/https/http-path/routing%2Fv1",
+                "https://example.com/routing/v1",
+            ),
+            (
+                "/dns/example.com/tcp/80/http/http-path/a b%25",
+                "http://example.com/a%20b%25",
+            ),
+        ] {
+            assert_eq!(addr(s).to_url().unwrap(), url, "{}", s);
+            assert_eq!(MultiAddr::from_url(url).unwrap(), addr(s), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_to_url_aliases() {
+        for (s, url) in [
+            ("/dns4/example.com/tcp/443/tls/http", "https://example.com"),
+            (
+                "/dns6/example.com/tcp/443/tls/sni/example.com/ws",
+                "wss://example.com",
+            ),
+            ("/ip4/1.2.3.4/tcp/443/http", "http://1.2.3.4:443"),
+            ("/ip4/1.2.3.4/tcp/80/tls/ws", "wss://1.2.3.4:80"),
+        ] {
+            assert_eq!(addr(s).to_url().unwrap(), url, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_from_url_aliases() {
+        for (url, s) in [
+            ("HTTPS://example.com/", "/dns/example.com/tcp/443/https"),
+            ("http://example.com:80", "/dns/example.com/tcp/80/http"),
+            ("ws://1.2.3.4:4001/", "/ip4/1.2.3.4/tcp/4001/ws"),
+        ] {
+            assert_eq!(MultiAddr::from_url(url).unwrap(), addr(s), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_to_url_errors() {
+        for (s, err) in [
+            ("/ip4/1.2.3.4/tcp/4001", UrlError::Incomplete),
+            ("/ip4/1.2.3.4/udp/443/quic-v1", UrlError::Unsupported("udp")),
+            (
+                "/dnsaddr/example.com/tcp/443/https",
+                UrlError::Unsupported("dnsaddr"),
+            ),
+            (
+                "/ip4/1.2.3.4/tcp/443/tls/sni/example.com/http",
+                UrlError::Unsupported("sni"),
+            ),
+            ("/ip4/1.2.3.4/tcp/443/tls", UrlError::Incomplete),
+            (
+                "/ip4/1.2.3.4/tcp/443/https/p2p/QmcgpsyWgH8Y8ajJz1Cu72KnS5uo2Aa2LpzU7kinSupNKC",
+                UrlError::Unsupported("p2p"),
+            ),
+            ("/ip6zone/eth0/tcp/80/http", UrlError::Unsupported("tcp")),
+        ] {
+            assert_eq!(addr(s).to_url(), Err(err), "{}", s);
+        }
+        assert_eq!(MultiAddr::new().to_url(), Err(UrlError::Incomplete));
+    }
+
+    #[test]
+    fn test_from_url_errors() {
+        for (url, err) in [
+            ("example.com", UrlError::InvalidUrl("scheme")),
+            (
+                "ftp://example.com",
+                UrlError::UnsupportedScheme("ftp".into()),
+            ),
+            ("https://example.com/?q=1", UrlError::InvalidUrl("query")),
+            ("https://example.com/#top", UrlError::InvalidUrl("fragment")),
+            ("https://user@example.com", UrlError::InvalidUrl("userinfo")),
+            ("https://", UrlError::InvalidUrl("host")),
+            ("https://:443", UrlError::InvalidUrl("host")),
+            ("https://[::1", UrlError::InvalidUrl("host")),
+            ("https://[1.2.3.4]", UrlError::InvalidUrl("host")),
+            ("https://[fe80::1%25]", UrlError::InvalidUrl("host")),
+            ("https://[fe80::1%25eth%2]", UrlError::InvalidUrl("host")),
+            ("https://[fe80::1%25eth%2F0]", UrlError::InvalidUrl("host")),
+            ("https://[::1]443", UrlError::InvalidUrl("host")),
+            ("https://example.com:", UrlError::InvalidUrl("port")),
+            ("https://example.com:65536", UrlError::InvalidUrl("port")),
+            ("https://example.com:0", UrlError::InvalidUrl("port")),
+            ("https://[::1]:0", UrlError::InvalidUrl("port")),
+            ("https://example.com/%zz", UrlError::InvalidUrl("path")),
+        ] {
+            assert_eq!(MultiAddr::from_url(url), Err(err), "{}", url);
+        }
+    }
+}
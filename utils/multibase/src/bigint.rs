@@ -1,5 +1,3 @@
-use std::ptr::copy_nonoverlapping;
-
 pub(crate) struct Bigint {
     chunks: Vec<u32>,
 }
@@ -9,29 +7,18 @@ impl Bigint {
         let remain = bytes.len() % 4;
         let len = bytes.len() / 4 + (remain > 0) as usize;
 
-        let mut chunks = Vec::with_capacity(len);
-
-        unsafe {
-            chunks.set_len(len);
-
-            let mut chunks_ptr = chunks.as_mut_ptr() as *mut u8;
-
-            if remain > 0 {
-                *chunks.get_unchecked_mut(0) = 0u32;
-                chunks_ptr = chunks_ptr.offset(4 - remain as isize);
-            }
-
-            copy_nonoverlapping(bytes.as_ptr(), chunks_ptr, bytes.len());
-        }
+        let mut buf = vec![0u8; len * 4];
+        buf[len * 4 - bytes.len()..].copy_from_slice(bytes);
 
-        for chunk in chunks.iter_mut() {
-            *chunk = u32::from_be(*chunk);
-        }
+        let chunks = buf
+            .chunks_exact(4)
+            .map(|chunk| u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect();
 
         Self { chunks }
     }
 
-    pub(crate) fn into_bytes(mut self) -> Vec<u8> {
+    pub(crate) fn into_bytes(self) -> Vec<u8> {
         let mut skip = 0;
 
         for chunk in self.chunks.iter() {
@@ -42,23 +29,11 @@ impl Bigint {
             skip += 4;
         }
 
-        let len = self.chunks.len() * 4 - skip as usize;
-        if len == 0 {
-            return Vec::new();
-        }
-
-        for chunk in self.chunks.iter_mut() {
-            *chunk = u32::to_be(*chunk);
-        }
-
-        let mut bytes = Vec::with_capacity(len);
-        unsafe {
-            bytes.set_len(len);
-            let chunks_ptr = (self.chunks.as_ptr() as *const u8).offset(skip as isize);
-            copy_nonoverlapping(chunks_ptr, bytes.as_mut_ptr(), len);
-        }
-
-        bytes
+        self.chunks
+            .iter()
+            .flat_map(|chunk| chunk.to_be_bytes())
+            .skip(skip as usize)
+            .collect()
     }
 
     pub(crate) fn modulo(&mut self, divider: u32) -> u32 {
@@ -70,7 +45,7 @@ impl Bigint {
             carry %= u64::from(divider);
         }
 
-        if let Some(0) = self.chunks.get(0) {
+        if let Some(0) = self.chunks.first() {
             self.chunks.remove(0);
         }
 
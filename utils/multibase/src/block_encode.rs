@@ -42,23 +42,13 @@ impl<'s, T: Copy + Hash + Eq> BlockEncoding<'s, T> {
 
     #[inline]
     pub(crate) fn encode_len(&self, input_bytes: usize) -> usize {
-        (input_bytes * 8 + self.bit - 1) / self.bit
+        (input_bytes * 8).div_ceil(self.bit)
     }
 
-    // return: input len, output len
+    // input_chars must not include any padding
     #[inline]
-    pub(crate) fn decode_len(&self, input_chars: usize) -> (usize, usize) {
-        if self.padding.is_some() {
-            (
-                input_chars / self.dec_len * self.dec_len,
-                input_chars / self.dec_len * self.enc_len,
-            )
-        } else {
-            (
-                input_chars - (self.bit * input_chars % 8) / self.bit,
-                self.bit * input_chars / 8,
-            )
-        }
+    pub(crate) fn decode_len(&self, input_chars: usize) -> usize {
+        self.bit * input_chars / 8
     }
 
     #[inline]
@@ -94,18 +84,23 @@ impl<'s, T: Copy + Hash + Eq> BlockEncoding<'s, T> {
         }
     }
 
-    pub(crate) fn decode(&self, input: &[T], output: &mut [u8]) {
+    pub(crate) fn decode(&self, input: &[T], output: &mut [u8]) -> bool {
         debug_assert!(output.len() <= self.enc_len);
         debug_assert_eq!(input.len(), self.encode_len(output.len()));
 
         let mut x = 0u64;
-        for i in 0..input.len() {
-            let y = self.rsymbols[&input[i]];
+        for (i, input) in input.iter().enumerate() {
+            let y = match self.rsymbols.get(input) {
+                Some(y) => *y,
+                _ => return false,
+            };
             x |= y << (self.bit * self.dec_order(i));
         }
         for (i, output) in output.iter_mut().enumerate() {
             *output = (x >> (8 * self.enc_order(i))) as u8;
         }
+
+        true
     }
 }
 
@@ -17,14 +17,16 @@ pub(crate) fn inner_encode(blk: &BlockEncoding<char>, input: &[u8], output: &mut
     );
 }
 
-pub(crate) fn inner_decode(blk: &BlockEncoding<char>, input: &[char], output: &mut [u8]) {
+pub(crate) fn inner_decode(blk: &BlockEncoding<char>, input: &[char], output: &mut [u8]) -> bool {
     let nblk = input.len() / blk.dec_len;
     for grp in 0..nblk {
         let input = unsafe { from_raw_parts(input.as_ptr().add(grp * blk.dec_len), blk.dec_len) };
         let output =
             unsafe { from_raw_parts_mut(output.as_mut_ptr().add(grp * blk.enc_len), blk.enc_len) };
 
-        blk.decode(input, output);
+        if !blk.decode(input, output) {
+            return false;
+        }
     }
 
     blk.decode(
@@ -48,23 +50,22 @@ macro_rules! encoding_declare {
                         $opt(&mut blk);
                 )+)?
 
-                let len = blk.encode_len(input.len()) $(+ {$prefix; 1})?;
-                let padding_len = blk.dec_len - len % blk.dec_len;
-
-                let mut output = Vec::with_capacity(len + padding_len);
-                unsafe { output.set_len(len) };
-
-                #[allow(unused_mut)]
-                let mut output_slice = output.as_mut_slice();
-                $(output_slice = {output_slice[0] = $prefix; &mut output_slice[1..]};)?
+                let len = blk.encode_len(input.len());
+                let padding_len = (blk.dec_len - len % blk.dec_len) % blk.dec_len;
 
-                $crate::inner_encode(&blk, input, output_slice);
+                let mut output = vec!['\0'; len];
+                $crate::inner_encode(&blk, input, &mut output);
 
                 if let Some(padding) = blk.padding {
                     output.resize(output.len() + padding_len, padding);
                 }
 
-                output.iter().collect()
+                #[allow(unused_mut)]
+                let mut result = String::with_capacity(output.len() + 1);
+                $(result.push($prefix);)?
+                result.extend(output.iter());
+
+                result
             }
         }
 
@@ -78,12 +79,22 @@ macro_rules! encoding_declare {
                     $opt(&mut blk);
                 )+)?
 
-                let (_, len) = blk.decode_len(input.len() - 1);
-                let mut output = Vec::with_capacity(len);
-                unsafe { output.set_len(len) };
+                let mut input: Vec<char> = input.chars().skip(1).collect();
+                if let Some(padding) = blk.padding {
+                    while input.last() == Some(&padding) {
+                        input.pop();
+                    }
+                }
+
+                let len = blk.decode_len(input.len());
+                if blk.encode_len(len) != input.len() {
+                    return Vec::new();
+                }
 
-                let input: Vec<char> = input.chars().skip(1).collect();
-                $crate::inner_decode(&blk, &input, &mut output);
+                let mut output = vec![0u8; len];
+                if !$crate::inner_decode(&blk, &input, &mut output) {
+                    return Vec::new();
+                }
 
                 output
             }
@@ -103,8 +114,7 @@ macro_rules! encoding_x_declare {
                 let big_pow = 32 / (32 - base.leading_zeros());
                 let big_base = base.pow(big_pow);
 
-                let mut output = Vec::with_capacity(input.len()$(+{$prefix; 1})?);
-                $(output.push($prefix);)?
+                let mut output = Vec::with_capacity(input.len() * 2);
 
                 let mut big = Bigint::from_bytes(input);
                 'fast: loop {
@@ -131,12 +141,19 @@ macro_rules! encoding_x_declare {
 
                 let leaders = input
                     .iter()
-                    .take(input.len() - 1)
+                    .take(input.len().saturating_sub(1))
                     .take_while(|i| **i == 0)
                     .map(|_| symbols[0]);
                 output.extend(leaders);
 
-                output.iter().collect()
+                #[allow(unused_mut)]
+                let mut result = String::with_capacity(output.len() + 1);
+                $(result.push($prefix);)?
+                if !input.is_empty() {
+                    result.extend(output.iter().rev());
+                }
+
+                result
             }
         }
 
@@ -147,8 +164,8 @@ macro_rules! encoding_x_declare {
                     rsymbols.insert(symbol, index as u32);
                 }
 
-                let base = $symbols.len() as u32;
-                let mut big = Bigint::from_bytes(&vec![0, 0, 0, 0]);
+                let base = rsymbols.len() as u32;
+                let mut big = Bigint::from_bytes(&[0, 0, 0, 0]);
                 for c in input.chars().skip(1) {
                     if let Some(carry) = rsymbols.get(&c) {
                         big.mul_add(base, *carry);
@@ -162,7 +179,11 @@ macro_rules! encoding_x_declare {
                     Some(leader) => leader,
                     _ => return Vec::new(),
                 };
-                let leaders = input.chars().take_while(|byte| *byte == leader).count();
+                let leaders = input
+                    .chars()
+                    .skip(1)
+                    .take_while(|byte| *byte == leader)
+                    .count();
 
                 for _ in 0..leaders {
                     bytes.insert(0, 0);
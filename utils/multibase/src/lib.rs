@@ -66,3 +66,147 @@ pub fn multibase_decode(input: &str) -> Vec<u8> {
         '🚀' => Base256Emoji;
     )
 }
+
+const BASE_NAMES: [(char, &str); 24] = [
+    ('0', "base2"),
+    ('7', "base8"),
+    ('9', "base10"),
+    ('f', "base16"),
+    ('F', "base16upper"),
+    ('v', "base32hex"),
+    ('V', "base32hexupper"),
+    ('t', "base32hexpad"),
+    ('T', "base32hexpadupper"),
+    ('b', "base32"),
+    ('B', "base32upper"),
+    ('c', "base32pad"),
+    ('C', "base32padupper"),
+    ('h', "base32z"),
+    ('k', "base36"),
+    ('K', "base36upper"),
+    ('z', "base58btc"),
+    ('Z', "base58flickr"),
+    ('m', "base64"),
+    ('M', "base64pad"),
+    ('u', "base64url"),
+    ('U', "base64urlpad"),
+    ('p', "proquint"),
+    ('🚀', "base256emoji"),
+];
+
+pub fn base_name(prefix: char) -> Option<&'static str> {
+    BASE_NAMES
+        .iter()
+        .find(|(code, _)| *code == prefix)
+        .map(|(_, name)| *name)
+}
+
+pub fn base_prefix(name: &str) -> Option<char> {
+    BASE_NAMES
+        .iter()
+        .find(|(_, base)| *base == name)
+        .map(|(code, _)| *code)
+}
+
+pub fn multibase_encode(prefix: char, input: &[u8]) -> String {
+    match prefix {
+        '0' => Base2::encode(input),
+        '7' => Base8::encode(input),
+
+        '9' => Base10::encode(input),
+
+        'f' => Base16::encode(input),
+        'F' => Base16Upper::encode(input),
+
+        'v' => Base32Hex::encode(input),
+        'V' => Base32HexUpper::encode(input),
+        't' => Base32HexPad::encode(input),
+        'T' => Base32HexPadUpper::encode(input),
+
+        'b' => Base32::encode(input),
+        'B' => Base32Upper::encode(input),
+        'c' => Base32Pad::encode(input),
+        'C' => Base32PadUpper::encode(input),
+
+        'h' => Base32Z::encode(input),
+
+        'k' => Base36::encode(input),
+        'K' => Base36Upper::encode(input),
+
+        'z' => Base58Bitcoin::encode(input),
+        'Z' => Base58Flickr::encode(input),
+
+        'm' => Base64::encode(input),
+        'M' => Base64Pad::encode(input),
+        'u' => Base64URL::encode(input),
+        'U' => Base64URLPad::encode(input),
+
+        'p' => Prouints::encode(input),
+
+        '🚀' => Base256Emoji::encode(input),
+
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spec_vectors() {
+        let input = b"yes mani !";
+
+        assert_eq!(Base8::encode(input), "7362625631006654133464440102");
+        assert_eq!(Base10::encode(input), "9573277761329450583662625");
+        assert_eq!(Base32Z::encode(input), "hxf1zgedpcfzg1ebb");
+        assert_eq!(Base32HexPad::encode(input), "tf5in683dc5n6i811");
+        assert_eq!(Base36::encode(input), "k2lcpzo5yikidynfl");
+        assert_eq!(Base58Flickr::encode(input), "Z7Pznk19XTTzBtx");
+        assert_eq!(Base58Bitcoin::encode(input), "z7paNL19xttacUY");
+        assert_eq!(Base64URLPad::encode(input), "UeWVzIG1hbmkgIQ==");
+
+        for encoded in [
+            "7362625631006654133464440102",
+            "9573277761329450583662625",
+            "hxf1zgedpcfzg1ebb",
+            "tf5in683dc5n6i811",
+            "k2lcpzo5yikidynfl",
+            "z7paNL19xttacUY",
+            "UeWVzIG1hbmkgIQ==",
+        ] {
+            assert_eq!(multibase_decode(encoded), input);
+        }
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        let input = b"\x00\x00yes mani !";
+
+        assert_eq!(Base58Bitcoin::encode(input), "z117paNL19xttacUY");
+        assert_eq!(Base36::encode(input), "k002lcpzo5yikidynfl");
+        assert_eq!(multibase_decode("z117paNL19xttacUY"), input);
+        assert_eq!(multibase_decode("k002lcpzo5yikidynfl"), input);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(multibase_decode("bjbswy3dpeblw64tmm!").is_empty());
+        assert!(multibase_decode("z0OIl").is_empty());
+        assert!(multibase_decode("bj").is_empty());
+        assert!(multibase_decode("?abc").is_empty());
+    }
+
+    #[test]
+    fn test_runtime_base() {
+        assert_eq!(base_prefix("base36"), Some('k'));
+        assert_eq!(base_name('z'), Some("base58btc"));
+        assert_eq!(base_prefix("base1"), None);
+
+        for (prefix, _) in BASE_NAMES {
+            let encoded = multibase_encode(prefix, b"yes mani !");
+            assert_eq!(encoded.chars().next(), Some(prefix));
+        }
+        assert!(multibase_encode('?', b"yes mani !").is_empty());
+    }
+}
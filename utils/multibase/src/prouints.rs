@@ -34,7 +34,7 @@ impl ProuintsBlockEncoding {
 
     #[inline]
     pub(crate) fn encode_len(input_bytes: usize) -> usize {
-        (input_bytes + 1) / 2 * 5
+        input_bytes.div_ceil(2) * 5
     }
 
     #[inline]
@@ -50,10 +50,10 @@ impl ProuintsBlockEncoding {
             x |= u16::from(*input) << (8 * self.dec_order(index));
         }
 
-        let consonant = vec![
+        let consonant = [
             'b', 'd', 'f', 'g', 'h', 'j', 'k', 'l', 'm', 'n', 'p', 'r', 's', 't', 'v', 'z',
         ];
-        let vowel = vec!['a', 'i', 'o', 'u'];
+        let vowel = ['a', 'i', 'o', 'u'];
 
         output[self.enc_order(0)] = consonant[(x & 0x000f) as usize];
         output[self.enc_order(1)] = vowel[((x >> 4) & 0x0003) as usize];
@@ -131,8 +131,8 @@ fn inner_encode(input: &[u8], output: &mut [char]) {
         output[5] = '-';
     }
 
-    if input.len() % 2 != 0 {
-        let input = vec![*input.last().unwrap(), 0u8];
+    if !input.len().is_multiple_of(2) {
+        let input = [*input.last().unwrap(), 0u8];
 
         blk.encode(&input, &mut output[nblk * 6..(nblk + 1) * 6 - 1]);
     }
@@ -154,13 +154,12 @@ impl Encoder for Prouints {
     fn encode(input: &[u8]) -> String {
         let len = ProuintsBlockEncoding::encode_len(input.len());
 
-        let mut output = Vec::with_capacity(len + len / 5 + 1);
-        unsafe { output.set_len(len + len / 5 + 1) }
+        let mut output = vec!['\0'; len + len / 5 + 1];
 
         output[0] = 'p';
         inner_encode(input, &mut output.as_mut_slice()[1..]);
 
-        unsafe { output.set_len(len + len / 5) };
+        output.truncate(len + len / 5);
         output.iter().collect()
     }
 }
@@ -169,10 +168,9 @@ impl Decoder for Prouints {
     fn decode(input: &str) -> Vec<u8> {
         let len = ProuintsBlockEncoding::decode_len(input.len() - 1);
 
-        let mut output = Vec::with_capacity(len);
-        unsafe { output.set_len(len) };
+        let mut output = vec![0u8; len];
 
-        let chars: Vec<char> = input.chars().skip(1).map(|b| b).collect();
+        let chars: Vec<char> = input.chars().skip(1).collect();
         inner_decode(&chars, &mut output);
 
         output
@@ -1,1054 +1,1053 @@
-pub fn to_code(val: &str) -> u64 {
+pub fn try_to_code(val: &str) -> Option<u64> {
     match val {
-        "identity" => 0x00,
-        "cidv1" => 0x01,
-        "cidv2" => 0x02,
-        "cidv3" => 0x03,
-        "ip4" => 0x04,
-        "tcp" => 0x06,
-        "sha1" => 0x11,
-        "sha2-256" => 0x12,
-        "sha2-512" => 0x13,
-        "sha3-512" => 0x14,
-        "sha3-384" => 0x15,
-        "sha3-256" => 0x16,
-        "sha3-224" => 0x17,
-        "shake-128" => 0x18,
-        "shake-256" => 0x19,
-        "keccak-224" => 0x1a,
-        "keccak-256" => 0x1b,
-        "keccak-384" => 0x1c,
-        "keccak-512" => 0x1d,
-        "blake3" => 0x1e,
-        "sha2-384" => 0x20,
-        "dccp" => 0x21,
-        "murmur3-x64-64" => 0x22,
-        "murmur3-32" => 0x23,
-        "ip6" => 0x29,
-        "ip6zone" => 0x2a,
-        "ipcidr" => 0x2b,
-        "path" => 0x2f,
-        "multicodec" => 0x30,
-        "multihash" => 0x31,
-        "multiaddr" => 0x32,
-        "multibase" => 0x33,
-        "dns" => 0x35,
-        "dns4" => 0x36,
-        "dns6" => 0x37,
-        "dnsaddr" => 0x38,
-        "protobuf" => 0x50,
-        "cbor" => 0x51,
-        "raw" => 0x55,
-        "dbl-sha2-256" => 0x56,
-        "rlp" => 0x60,
-        "bencode" => 0x63,
-        "dag-pb" => 0x70,
-        "dag-cbor" => 0x71,
-        "libp2p-key" => 0x72,
-        "git-raw" => 0x78,
-        "torrent-info" => 0x7b,
-        "torrent-file" => 0x7c,
-        "leofcoin-block" => 0x81,
-        "leofcoin-tx" => 0x82,
-        "leofcoin-pr" => 0x83,
-        "sctp" => 0x84,
-        "dag-jose" => 0x85,
-        "dag-cose" => 0x86,
-        "eth-block" => 0x90,
-        "eth-block-list" => 0x91,
-        "eth-tx-trie" => 0x92,
-        "eth-tx" => 0x93,
-        "eth-tx-receipt-trie" => 0x94,
-        "eth-tx-receipt" => 0x95,
-        "eth-state-trie" => 0x96,
-        "eth-account-snapshot" => 0x97,
-        "eth-storage-trie" => 0x98,
-        "eth-receipt-log-trie" => 0x99,
-        "eth-reciept-log" => 0x9a,
-        "aes-128" => 0xa0,
-        "aes-192" => 0xa1,
-        "aes-256" => 0xa2,
-        "chacha-128" => 0xa3,
-        "chacha-256" => 0xa4,
-        "bitcoin-block" => 0xb0,
-        "bitcoin-tx" => 0xb1,
-        "bitcoin-witness-commitment" => 0xb2,
-        "zcash-block" => 0xc0,
-        "zcash-tx" => 0xc1,
-        "caip-50" => 0xca,
-        "streamid" => 0xce,
-        "stellar-block" => 0xd0,
-        "stellar-tx" => 0xd1,
-        "md4" => 0xd4,
-        "md5" => 0xd5,
-        "decred-block" => 0xe0,
-        "decred-tx" => 0xe1,
-        "ipld" => 0xe2,
-        "ipfs" => 0xe3,
-        "swarm" => 0xe4,
-        "ipns" => 0xe5,
-        "zeronet" => 0xe6,
-        "secp256k1-pub" => 0xe7,
-        "dnslink" => 0xe8,
-        "bls12_381-g1-pub" => 0xea,
-        "bls12_381-g2-pub" => 0xeb,
-        "x25519-pub" => 0xec,
-        "ed25519-pub" => 0xed,
-        "bls12_381-g1g2-pub" => 0xee,
-        "dash-block" => 0xf0,
-        "dash-tx" => 0xf1,
-        "swarm-manifest" => 0xfa,
-        "swarm-feed" => 0xfb,
-        "beeson" => 0xfc,
-        "udp" => 0x0111,
-        "p2p-webrtc-star" => 0x0113,
-        "p2p-webrtc-direct" => 0x0114,
-        "p2p-stardust" => 0x0115,
-        "webrtc" => 0x0118,
-        "p2p-circuit" => 0x0122,
-        "dag-json" => 0x0129,
-        "udt" => 0x012d,
-        "utp" => 0x012e,
-        "crc32" => 0x0132,
-        "crc64-ecma" => 0x0164,
-        "unix" => 0x0190,
-        "thread" => 0x0196,
-        "p2p" => 0x01a5,
-        "https" => 0x01bb,
-        "onion" => 0x01bc,
-        "onion3" => 0x01bd,
-        "garlic64" => 0x01be,
-        "garlic32" => 0x01bf,
-        "tls" => 0x01c0,
-        "sni" => 0x01c1,
-        "noise" => 0x01c6,
-        "quic" => 0x01cc,
-        "quic-v1" => 0x01cd,
-        "webtransport" => 0x01d1,
-        "certhash" => 0x01d2,
-        "ws" => 0x01dd,
-        "wss" => 0x01de,
-        "p2p-websocket-star" => 0x01df,
-        "http" => 0x01e0,
-        "swhid-1-snp" => 0x01f0,
-        "json" => 0x0200,
-        "messagepack" => 0x0201,
-        "car" => 0x0202,
-        "libp2p-peer-record" => 0x0301,
-        "libp2p-relay-rsvp" => 0x0302,
-        "memorytransport" => 0x0309,
-        "car-index-sorted" => 0x0400,
-        "car-multihash-index-sorted" => 0x0401,
-        "transport-bitswap" => 0x0900,
-        "transport-graphsync-filecoinv1" => 0x0910,
-        "multidid" => 0x0d1d,
-        "sha2-256-trunc254-padded" => 0x1012,
-        "sha2-224" => 0x1013,
-        "sha2-512-224" => 0x1014,
-        "sha2-512-256" => 0x1015,
-        "murmur3-x64-128" => 0x1022,
-        "ripemd-128" => 0x1052,
-        "ripemd-160" => 0x1053,
-        "ripemd-256" => 0x1054,
-        "ripemd-320" => 0x1055,
-        "x11" => 0x1100,
-        "p256-pub" => 0x1200,
-        "p384-pub" => 0x1201,
-        "p521-pub" => 0x1202,
-        "ed448-pub" => 0x1203,
-        "x448-pub" => 0x1204,
-        "rsa-pub" => 0x1205,
-        "sm2-pub" => 0x1206,
-        "ed25519-priv" => 0x1300,
-        "secp256k1-priv" => 0x1301,
-        "x25519-priv" => 0x1302,
-        "rsa-priv" => 0x1305,
-        "kangarootwelve" => 0x1d01,
-        "silverpine" => 0x3f42,
-        "sm3-256" => 0x534d,
-        "blake2b-8" => 0xb201,
-        "blake2b-16" => 0xb202,
-        "blake2b-24" => 0xb203,
-        "blake2b-32" => 0xb204,
-        "blake2b-40" => 0xb205,
-        "blake2b-48" => 0xb206,
-        "blake2b-56" => 0xb207,
-        "blake2b-64" => 0xb208,
-        "blake2b-72" => 0xb209,
-        "blake2b-80" => 0xb20a,
-        "blake2b-88" => 0xb20b,
-        "blake2b-96" => 0xb20c,
-        "blake2b-104" => 0xb20d,
-        "blake2b-112" => 0xb20e,
-        "blake2b-120" => 0xb20f,
-        "blake2b-128" => 0xb210,
-        "blake2b-136" => 0xb211,
-        "blake2b-144" => 0xb212,
-        "blake2b-152" => 0xb213,
-        "blake2b-160" => 0xb214,
-        "blake2b-168" => 0xb215,
-        "blake2b-176" => 0xb216,
-        "blake2b-184" => 0xb217,
-        "blake2b-192" => 0xb218,
-        "blake2b-200" => 0xb219,
-        "blake2b-208" => 0xb21a,
-        "blake2b-216" => 0xb21b,
-        "blake2b-224" => 0xb21c,
-        "blake2b-232" => 0xb21d,
-        "blake2b-240" => 0xb21e,
-        "blake2b-248" => 0xb21f,
-        "blake2b-256" => 0xb220,
-        "blake2b-264" => 0xb221,
-        "blake2b-272" => 0xb222,
-        "blake2b-280" => 0xb223,
-        "blake2b-288" => 0xb224,
-        "blake2b-296" => 0xb225,
-        "blake2b-304" => 0xb226,
-        "blake2b-312" => 0xb227,
-        "blake2b-320" => 0xb228,
-        "blake2b-328" => 0xb229,
-        "blake2b-336" => 0xb22a,
-        "blake2b-344" => 0xb22b,
-        "blake2b-352" => 0xb22c,
-        "blake2b-360" => 0xb22d,
-        "blake2b-368" => 0xb22e,
-        "blake2b-376" => 0xb22f,
-        "blake2b-384" => 0xb230,
-        "blake2b-392" => 0xb231,
-        "blake2b-400" => 0xb232,
-        "blake2b-408" => 0xb233,
-        "blake2b-416" => 0xb234,
-        "blake2b-424" => 0xb235,
-        "blake2b-432" => 0xb236,
-        "blake2b-440" => 0xb237,
-        "blake2b-448" => 0xb238,
-        "blake2b-456" => 0xb239,
-        "blake2b-464" => 0xb23a,
-        "blake2b-472" => 0xb23b,
-        "blake2b-480" => 0xb23c,
-        "blake2b-488" => 0xb23d,
-        "blake2b-496" => 0xb23e,
-        "blake2b-504" => 0xb23f,
-        "blake2b-512" => 0xb240,
-        "blake2s-8" => 0xb241,
-        "blake2s-16" => 0xb242,
-        "blake2s-24" => 0xb243,
-        "blake2s-32" => 0xb244,
-        "blake2s-40" => 0xb245,
-        "blake2s-48" => 0xb246,
-        "blake2s-56" => 0xb247,
-        "blake2s-64" => 0xb248,
-        "blake2s-72" => 0xb249,
-        "blake2s-80" => 0xb24a,
-        "blake2s-88" => 0xb24b,
-        "blake2s-96" => 0xb24c,
-        "blake2s-104" => 0xb24d,
-        "blake2s-112" => 0xb24e,
-        "blake2s-120" => 0xb24f,
-        "blake2s-128" => 0xb250,
-        "blake2s-136" => 0xb251,
-        "blake2s-144" => 0xb252,
-        "blake2s-152" => 0xb253,
-        "blake2s-160" => 0xb254,
-        "blake2s-168" => 0xb255,
-        "blake2s-176" => 0xb256,
-        "blake2s-184" => 0xb257,
-        "blake2s-192" => 0xb258,
-        "blake2s-200" => 0xb259,
-        "blake2s-208" => 0xb25a,
-        "blake2s-216" => 0xb25b,
-        "blake2s-224" => 0xb25c,
-        "blake2s-232" => 0xb25d,
-        "blake2s-240" => 0xb25e,
-        "blake2s-248" => 0xb25f,
-        "blake2s-256" => 0xb260,
-        "skein256-8" => 0xb301,
-        "skein256-16" => 0xb302,
-        "skein256-24" => 0xb303,
-        "skein256-32" => 0xb304,
-        "skein256-40" => 0xb305,
-        "skein256-48" => 0xb306,
-        "skein256-56" => 0xb307,
-        "skein256-64" => 0xb308,
-        "skein256-72" => 0xb309,
-        "skein256-80" => 0xb30a,
-        "skein256-88" => 0xb30b,
-        "skein256-96" => 0xb30c,
-        "skein256-104" => 0xb30d,
-        "skein256-112" => 0xb30e,
-        "skein256-120" => 0xb30f,
-        "skein256-128" => 0xb310,
-        "skein256-136" => 0xb311,
-        "skein256-144" => 0xb312,
-        "skein256-152" => 0xb313,
-        "skein256-160" => 0xb314,
-        "skein256-168" => 0xb315,
-        "skein256-176" => 0xb316,
-        "skein256-184" => 0xb317,
-        "skein256-192" => 0xb318,
-        "skein256-200" => 0xb319,
-        "skein256-208" => 0xb31a,
-        "skein256-216" => 0xb31b,
-        "skein256-224" => 0xb31c,
-        "skein256-232" => 0xb31d,
-        "skein256-240" => 0xb31e,
-        "skein256-248" => 0xb31f,
-        "skein256-256" => 0xb320,
-        "skein512-8" => 0xb321,
-        "skein512-16" => 0xb322,
-        "skein512-24" => 0xb323,
-        "skein512-32" => 0xb324,
-        "skein512-40" => 0xb325,
-        "skein512-48" => 0xb326,
-        "skein512-56" => 0xb327,
-        "skein512-64" => 0xb328,
-        "skein512-72" => 0xb329,
-        "skein512-80" => 0xb32a,
-        "skein512-88" => 0xb32b,
-        "skein512-96" => 0xb32c,
-        "skein512-104" => 0xb32d,
-        "skein512-112" => 0xb32e,
-        "skein512-120" => 0xb32f,
-        "skein512-128" => 0xb330,
-        "skein512-136" => 0xb331,
-        "skein512-144" => 0xb332,
-        "skein512-152" => 0xb333,
-        "skein512-160" => 0xb334,
-        "skein512-168" => 0xb335,
-        "skein512-176" => 0xb336,
-        "skein512-184" => 0xb337,
-        "skein512-192" => 0xb338,
-        "skein512-200" => 0xb339,
-        "skein512-208" => 0xb33a,
-        "skein512-216" => 0xb33b,
-        "skein512-224" => 0xb33c,
-        "skein512-232" => 0xb33d,
-        "skein512-240" => 0xb33e,
-        "skein512-248" => 0xb33f,
-        "skein512-256" => 0xb340,
-        "skein512-264" => 0xb341,
-        "skein512-272" => 0xb342,
-        "skein512-280" => 0xb343,
-        "skein512-288" => 0xb344,
-        "skein512-296" => 0xb345,
-        "skein512-304" => 0xb346,
-        "skein512-312" => 0xb347,
-        "skein512-320" => 0xb348,
-        "skein512-328" => 0xb349,
-        "skein512-336" => 0xb34a,
-        "skein512-344" => 0xb34b,
-        "skein512-352" => 0xb34c,
-        "skein512-360" => 0xb34d,
-        "skein512-368" => 0xb34e,
-        "skein512-376" => 0xb34f,
-        "skein512-384" => 0xb350,
-        "skein512-392" => 0xb351,
-        "skein512-400" => 0xb352,
-        "skein512-408" => 0xb353,
-        "skein512-416" => 0xb354,
-        "skein512-424" => 0xb355,
-        "skein512-432" => 0xb356,
-        "skein512-440" => 0xb357,
-        "skein512-448" => 0xb358,
-        "skein512-456" => 0xb359,
-        "skein512-464" => 0xb35a,
-        "skein512-472" => 0xb35b,
-        "skein512-480" => 0xb35c,
-        "skein512-488" => 0xb35d,
-        "skein512-496" => 0xb35e,
-        "skein512-504" => 0xb35f,
-        "skein512-512" => 0xb360,
-        "skein1024-8" => 0xb361,
-        "skein1024-16" => 0xb362,
-        "skein1024-24" => 0xb363,
-        "skein1024-32" => 0xb364,
-        "skein1024-40" => 0xb365,
-        "skein1024-48" => 0xb366,
-        "skein1024-56" => 0xb367,
-        "skein1024-64" => 0xb368,
-        "skein1024-72" => 0xb369,
-        "skein1024-80" => 0xb36a,
-        "skein1024-88" => 0xb36b,
-        "skein1024-96" => 0xb36c,
-        "skein1024-104" => 0xb36d,
-        "skein1024-112" => 0xb36e,
-        "skein1024-120" => 0xb36f,
-        "skein1024-128" => 0xb370,
-        "skein1024-136" => 0xb371,
-        "skein1024-144" => 0xb372,
-        "skein1024-152" => 0xb373,
-        "skein1024-160" => 0xb374,
-        "skein1024-168" => 0xb375,
-        "skein1024-176" => 0xb376,
-        "skein1024-184" => 0xb377,
-        "skein1024-192" => 0xb378,
-        "skein1024-200" => 0xb379,
-        "skein1024-208" => 0xb37a,
-        "skein1024-216" => 0xb37b,
-        "skein1024-224" => 0xb37c,
-        "skein1024-232" => 0xb37d,
-        "skein1024-240" => 0xb37e,
-        "skein1024-248" => 0xb37f,
-        "skein1024-256" => 0xb380,
-        "skein1024-264" => 0xb381,
-        "skein1024-272" => 0xb382,
-        "skein1024-280" => 0xb383,
-        "skein1024-288" => 0xb384,
-        "skein1024-296" => 0xb385,
-        "skein1024-304" => 0xb386,
-        "skein1024-312" => 0xb387,
-        "skein1024-320" => 0xb388,
-        "skein1024-328" => 0xb389,
-        "skein1024-336" => 0xb38a,
-        "skein1024-344" => 0xb38b,
-        "skein1024-352" => 0xb38c,
-        "skein1024-360" => 0xb38d,
-        "skein1024-368" => 0xb38e,
-        "skein1024-376" => 0xb38f,
-        "skein1024-384" => 0xb390,
-        "skein1024-392" => 0xb391,
-        "skein1024-400" => 0xb392,
-        "skein1024-408" => 0xb393,
-        "skein1024-416" => 0xb394,
-        "skein1024-424" => 0xb395,
-        "skein1024-432" => 0xb396,
-        "skein1024-440" => 0xb397,
-        "skein1024-448" => 0xb398,
-        "skein1024-456" => 0xb399,
-        "skein1024-464" => 0xb39a,
-        "skein1024-472" => 0xb39b,
-        "skein1024-480" => 0xb39c,
-        "skein1024-488" => 0xb39d,
-        "skein1024-496" => 0xb39e,
-        "skein1024-504" => 0xb39f,
-        "skein1024-512" => 0xb3a0,
-        "skein1024-520" => 0xb3a1,
-        "skein1024-528" => 0xb3a2,
-        "skein1024-536" => 0xb3a3,
-        "skein1024-544" => 0xb3a4,
-        "skein1024-552" => 0xb3a5,
-        "skein1024-560" => 0xb3a6,
-        "skein1024-568" => 0xb3a7,
-        "skein1024-576" => 0xb3a8,
-        "skein1024-584" => 0xb3a9,
-        "skein1024-592" => 0xb3aa,
-        "skein1024-600" => 0xb3ab,
-        "skein1024-608" => 0xb3ac,
-        "skein1024-616" => 0xb3ad,
-        "skein1024-624" => 0xb3ae,
-        "skein1024-632" => 0xb3af,
-        "skein1024-640" => 0xb3b0,
-        "skein1024-648" => 0xb3b1,
-        "skein1024-656" => 0xb3b2,
-        "skein1024-664" => 0xb3b3,
-        "skein1024-672" => 0xb3b4,
-        "skein1024-680" => 0xb3b5,
-        "skein1024-688" => 0xb3b6,
-        "skein1024-696" => 0xb3b7,
-        "skein1024-704" => 0xb3b8,
-        "skein1024-712" => 0xb3b9,
-        "skein1024-720" => 0xb3ba,
-        "skein1024-728" => 0xb3bb,
-        "skein1024-736" => 0xb3bc,
-        "skein1024-744" => 0xb3bd,
-        "skein1024-752" => 0xb3be,
-        "skein1024-760" => 0xb3bf,
-        "skein1024-768" => 0xb3c0,
-        "skein1024-776" => 0xb3c1,
-        "skein1024-784" => 0xb3c2,
-        "skein1024-792" => 0xb3c3,
-        "skein1024-800" => 0xb3c4,
-        "skein1024-808" => 0xb3c5,
-        "skein1024-816" => 0xb3c6,
-        "skein1024-824" => 0xb3c7,
-        "skein1024-832" => 0xb3c8,
-        "skein1024-840" => 0xb3c9,
-        "skein1024-848" => 0xb3ca,
-        "skein1024-856" => 0xb3cb,
-        "skein1024-864" => 0xb3cc,
-        "skein1024-872" => 0xb3cd,
-        "skein1024-880" => 0xb3ce,
-        "skein1024-888" => 0xb3cf,
-        "skein1024-896" => 0xb3d0,
-        "skein1024-904" => 0xb3d1,
-        "skein1024-912" => 0xb3d2,
-        "skein1024-920" => 0xb3d3,
-        "skein1024-928" => 0xb3d4,
-        "skein1024-936" => 0xb3d5,
-        "skein1024-944" => 0xb3d6,
-        "skein1024-952" => 0xb3d7,
-        "skein1024-960" => 0xb3d8,
-        "skein1024-968" => 0xb3d9,
-        "skein1024-976" => 0xb3da,
-        "skein1024-984" => 0xb3db,
-        "skein1024-992" => 0xb3dc,
-        "skein1024-1000" => 0xb3dd,
-        "skein1024-1008" => 0xb3de,
-        "skein1024-1016" => 0xb3df,
-        "skein1024-1024" => 0xb3e0,
-        "xxh-32" => 0xb3e1,
-        "xxh-64" => 0xb3e2,
-        "xxh3-64" => 0xb3e3,
-        "xxh3-128" => 0xb3e4,
-        "poseidon-bls12_381-a2-fc1" => 0xb401,
-        "poseidon-bls12_381-a2-fc1-sc" => 0xb402,
-        "urdca-2015-canon" => 0xb403,
-        "ssz" => 0xb501,
-        "ssz-sha2-256-bmt" => 0xb502,
-        "json-jcs" => 0xb601,
-        "iscc" => 0xcc01,
-        "zeroxcert-imprint-256" => 0xce11,
-        "varsig" => 0xd000,
-        "es256k" => 0xd0e7,
-        "bls-12381-g1-sig" => 0xd0ea,
-        "bls-12381-g2-sig" => 0xd0eb,
-        "eddsa" => 0xd0ed,
-        "eip-191" => 0xd191,
-        "fil-commitment-unsealed" => 0xf101,
-        "fil-commitment-sealed" => 0xf102,
-        "plaintextv2" => 0x706c61,
-        "holochain-adr-v0" => 0x807124,
-        "holochain-adr-v1" => 0x817124,
-        "holochain-key-v0" => 0x947124,
-        "holochain-key-v1" => 0x957124,
-        "holochain-sig-v0" => 0xa27124,
-        "holochain-sig-v1" => 0xa37124,
-        "skynet-ns" => 0xb19910,
-        "arweave-ns" => 0xb29910,
-        "subspace-ns" => 0xb39910,
-        "kumandra-ns" => 0xb49910,
-        "es256" => 0xd01200,
-        "es284" => 0xd01201,
-        "es512" => 0xd01202,
-        "rs256" => 0xd01205,
-        _ => unreachable!(),
+        "identity" => Some(0x00),
+        "cidv1" => Some(0x01),
+        "cidv2" => Some(0x02),
+        "cidv3" => Some(0x03),
+        "ip4" => Some(0x04),
+        "tcp" => Some(0x06),
+        "sha1" => Some(0x11),
+        "sha2-256" => Some(0x12),
+        "sha2-512" => Some(0x13),
+        "sha3-512" => Some(0x14),
+        "sha3-384" => Some(0x15),
+        "sha3-256" => Some(0x16),
+        "sha3-224" => Some(0x17),
+        "shake-128" => Some(0x18),
+        "shake-256" => Some(0x19),
+        "keccak-224" => Some(0x1a),
+        "keccak-256" => Some(0x1b),
+        "keccak-384" => Some(0x1c),
+        "keccak-512" => Some(0x1d),
+        "blake3" => Some(0x1e),
+        "sha2-384" => Some(0x20),
+        "dccp" => Some(0x21),
+        "murmur3-x64-64" => Some(0x22),
+        "murmur3-32" => Some(0x23),
+        "ip6" => Some(0x29),
+        "ip6zone" => Some(0x2a),
+        "ipcidr" => Some(0x2b),
+        "path" => Some(0x2f),
+        "multicodec" => Some(0x30),
+        "multihash" => Some(0x31),
+        "multiaddr" => Some(0x32),
+        "multibase" => Some(0x33),
+        "dns" => Some(0x35),
+        "dns4" => Some(0x36),
+        "dns6" => Some(0x37),
+        "dnsaddr" => Some(0x38),
+        "protobuf" => Some(0x50),
+        "cbor" => Some(0x51),
+        "raw" => Some(0x55),
+        "dbl-sha2-256" => Some(0x56),
+        "rlp" => Some(0x60),
+        "bencode" => Some(0x63),
+        "dag-pb" => Some(0x70),
+        "dag-cbor" => Some(0x71),
+        "libp2p-key" => Some(0x72),
+        "git-raw" => Some(0x78),
+        "torrent-info" => Some(0x7b),
+        "torrent-file" => Some(0x7c),
+        "leofcoin-block" => Some(0x81),
+        "leofcoin-tx" => Some(0x82),
+        "leofcoin-pr" => Some(0x83),
+        "sctp" => Some(0x84),
+        "dag-jose" => Some(0x85),
+        "dag-cose" => Some(0x86),
+        "eth-block" => Some(0x90),
+        "eth-block-list" => Some(0x91),
+        "eth-tx-trie" => Some(0x92),
+        "eth-tx" => Some(0x93),
+        "eth-tx-receipt-trie" => Some(0x94),
+        "eth-tx-receipt" => Some(0x95),
+        "eth-state-trie" => Some(0x96),
+        "eth-account-snapshot" => Some(0x97),
+        "eth-storage-trie" => Some(0x98),
+        "eth-receipt-log-trie" => Some(0x99),
+        "eth-reciept-log" => Some(0x9a),
+        "aes-128" => Some(0xa0),
+        "aes-192" => Some(0xa1),
+        "aes-256" => Some(0xa2),
+        "chacha-128" => Some(0xa3),
+        "chacha-256" => Some(0xa4),
+        "bitcoin-block" => Some(0xb0),
+        "bitcoin-tx" => Some(0xb1),
+        "bitcoin-witness-commitment" => Some(0xb2),
+        "zcash-block" => Some(0xc0),
+        "zcash-tx" => Some(0xc1),
+        "caip-50" => Some(0xca),
+        "streamid" => Some(0xce),
+        "stellar-block" => Some(0xd0),
+        "stellar-tx" => Some(0xd1),
+        "md4" => Some(0xd4),
+        "md5" => Some(0xd5),
+        "decred-block" => Some(0xe0),
+        "decred-tx" => Some(0xe1),
+        "ipld" => Some(0xe2),
+        "ipfs" => Some(0xe3),
+        "swarm" => Some(0xe4),
+        "ipns" => Some(0xe5),
+        "zeronet" => Some(0xe6),
+        "secp256k1-pub" => Some(0xe7),
+        "dnslink" => Some(0xe8),
+        "bls12_381-g1-pub" => Some(0xea),
+        "bls12_381-g2-pub" => Some(0xeb),
+        "x25519-pub" => Some(0xec),
+        "ed25519-pub" => Some(0xed),
+        "bls12_381-g1g2-pub" => Some(0xee),
+        "dash-block" => Some(0xf0),
+        "dash-tx" => Some(0xf1),
+        "swarm-manifest" => Some(0xfa),
+        "swarm-feed" => Some(0xfb),
+        "beeson" => Some(0xfc),
+        "udp" => Some(0x0111),
+        "p2p-webrtc-star" => Some(0x0113),
+        "p2p-webrtc-direct" => Some(0x0114),
+        "p2p-stardust" => Some(0x0115),
+        "webrtc" => Some(0x0118),
+        "p2p-circuit" => Some(0x0122),
+        "dag-json" => Some(0x0129),
+        "udt" => Some(0x012d),
+        "utp" => Some(0x012e),
+        "crc32" => Some(0x0132),
+        "crc64-ecma" => Some(0x0164),
+        "unix" => Some(0x0190),
+        "thread" => Some(0x0196),
+        "p2p" => Some(0x01a5),
+        "https" => Some(0x01bb),
+        "onion" => Some(0x01bc),
+        "onion3" => Some(0x01bd),
+        "garlic64" => Some(0x01be),
+        "garlic32" => Some(0x01bf),
+        "tls" => Some(0x01c0),
+        "sni" => Some(0x01c1),
+        "noise" => Some(0x01c6),
+        "quic" => Some(0x01cc),
+        "quic-v1" => Some(0x01cd),
+        "webtransport" => Some(0x01d1),
+        "certhash" => Some(0x01d2),
+        "ws" => Some(0x01dd),
+        "wss" => Some(0x01de),
+        "p2p-websocket-star" => Some(0x01df),
+        "http" => Some(0x01e0),
+        "swhid-1-snp" => Some(0x01f0),
+        "json" => Some(0x0200),
+        "messagepack" => Some(0x0201),
+        "car" => Some(0x0202),
+        "libp2p-peer-record" => Some(0x0301),
+        "libp2p-relay-rsvp" => Some(0x0302),
+        "memorytransport" => Some(0x0309),
+        "car-index-sorted" => Some(0x0400),
+        "car-multihash-index-sorted" => Some(0x0401),
+        "transport-bitswap" => Some(0x0900),
+        "transport-graphsync-filecoinv1" => Some(0x0910),
+        "multidid" => Some(0x0d1d),
+        "sha2-256-trunc254-padded" => Some(0x1012),
+        "sha2-224" => Some(0x1013),
+        "sha2-512-224" => Some(0x1014),
+        "sha2-512-256" => Some(0x1015),
+        "murmur3-x64-128" => Some(0x1022),
+        "ripemd-128" => Some(0x1052),
+        "ripemd-160" => Some(0x1053),
+        "ripemd-256" => Some(0x1054),
+        "ripemd-320" => Some(0x1055),
+        "x11" => Some(0x1100),
+        "p256-pub" => Some(0x1200),
+        "p384-pub" => Some(0x1201),
+        "p521-pub" => Some(0x1202),
+        "ed448-pub" => Some(0x1203),
+        "x448-pub" => Some(0x1204),
+        "rsa-pub" => Some(0x1205),
+        "sm2-pub" => Some(0x1206),
+        "ed25519-priv" => Some(0x1300),
+        "secp256k1-priv" => Some(0x1301),
+        "x25519-priv" => Some(0x1302),
+        "rsa-priv" => Some(0x1305),
+        "kangarootwelve" => Some(0x1d01),
+        "silverpine" => Some(0x3f42),
+        "sm3-256" => Some(0x534d),
+        "blake2b-8" => Some(0xb201),
+        "blake2b-16" => Some(0xb202),
+        "blake2b-24" => Some(0xb203),
+        "blake2b-32" => Some(0xb204),
+        "blake2b-40" => Some(0xb205),
+        "blake2b-48" => Some(0xb206),
+        "blake2b-56" => Some(0xb207),
+        "blake2b-64" => Some(0xb208),
+        "blake2b-72" => Some(0xb209),
+        "blake2b-80" => Some(0xb20a),
+        "blake2b-88" => Some(0xb20b),
+        "blake2b-96" => Some(0xb20c),
+        "blake2b-104" => Some(0xb20d),
+        "blake2b-112" => Some(0xb20e),
+        "blake2b-120" => Some(0xb20f),
+        "blake2b-128" => Some(0xb210),
+        "blake2b-136" => Some(0xb211),
+        "blake2b-144" => Some(0xb212),
+        "blake2b-152" => Some(0xb213),
+        "blake2b-160" => Some(0xb214),
+        "blake2b-168" => Some(0xb215),
+        "blake2b-176" => Some(0xb216),
+        "blake2b-184" => Some(0xb217),
+        "blake2b-192" => Some(0xb218),
+        "blake2b-200" => Some(0xb219),
+        "blake2b-208" => Some(0xb21a),
+        "blake2b-216" => Some(0xb21b),
+        "blake2b-224" => Some(0xb21c),
+        "blake2b-232" => Some(0xb21d),
+        "blake2b-240" => Some(0xb21e),
+        "blake2b-248" => Some(0xb21f),
+        "blake2b-256" => Some(0xb220),
+        "blake2b-264" => Some(0xb221),
+        "blake2b-272" => Some(0xb222),
+        "blake2b-280" => Some(0xb223),
+        "blake2b-288" => Some(0xb224),
+        "blake2b-296" => Some(0xb225),
+        "blake2b-304" => Some(0xb226),
+        "blake2b-312" => Some(0xb227),
+        "blake2b-320" => Some(0xb228),
+        "blake2b-328" => Some(0xb229),
+        "blake2b-336" => Some(0xb22a),
+        "blake2b-344" => Some(0xb22b),
+        "blake2b-352" => Some(0xb22c),
+        "blake2b-360" => Some(0xb22d),
+        "blake2b-368" => Some(0xb22e),
+        "blake2b-376" => Some(0xb22f),
+        "blake2b-384" => Some(0xb230),
+        "blake2b-392" => Some(0xb231),
+        "blake2b-400" => Some(0xb232),
+        "blake2b-408" => Some(0xb233),
+        "blake2b-416" => Some(0xb234),
+        "blake2b-424" => Some(0xb235),
+        "blake2b-432" => Some(0xb236),
+        "blake2b-440" => Some(0xb237),
+        "blake2b-448" => Some(0xb238),
+        "blake2b-456" => Some(0xb239),
+        "blake2b-464" => Some(0xb23a),
+        "blake2b-472" => Some(0xb23b),
+        "blake2b-480" => Some(0xb23c),
+        "blake2b-488" => Some(0xb23d),
+        "blake2b-496" => Some(0xb23e),
+        "blake2b-504" => Some(0xb23f),
+        "blake2b-512" => Some(0xb240),
+        "blake2s-8" => Some(0xb241),
+        "blake2s-16" => Some(0xb242),
+        "blake2s-24" => Some(0xb243),
+        "blake2s-32" => Some(0xb244),
+        "blake2s-40" => Some(0xb245),
+        "blake2s-48" => Some(0xb246),
+        "blake2s-56" => Some(0xb247),
+        "blake2s-64" => Some(0xb248),
+        "blake2s-72" => Some(0xb249),
+        "blake2s-80" => Some(0xb24a),
+        "blake2s-88" => Some(0xb24b),
+        "blake2s-96" => Some(0xb24c),
+        "blake2s-104" => Some(0xb24d),
+        "blake2s-112" => Some(0xb24e),
+        "blake2s-120" => Some(0xb24f),
+        "blake2s-128" => Some(0xb250),
+        "blake2s-136" => Some(0xb251),
+        "blake2s-144" => Some(0xb252),
+        "blake2s-152" => Some(0xb253),
+        "blake2s-160" => Some(0xb254),
+        "blake2s-168" => Some(0xb255),
+        "blake2s-176" => Some(0xb256),
+        "blake2s-184" => Some(0xb257),
+        "blake2s-192" => Some(0xb258),
+        "blake2s-200" => Some(0xb259),
+        "blake2s-208" => Some(0xb25a),
+        "blake2s-216" => Some(0xb25b),
+        "blake2s-224" => Some(0xb25c),
+        "blake2s-232" => Some(0xb25d),
+        "blake2s-240" => Some(0xb25e),
+        "blake2s-248" => Some(0xb25f),
+        "blake2s-256" => Some(0xb260),
+        "skein256-8" => Some(0xb301),
+        "skein256-16" => Some(0xb302),
+        "skein256-24" => Some(0xb303),
+        "skein256-32" => Some(0xb304),
+        "skein256-40" => Some(0xb305),
+        "skein256-48" => Some(0xb306),
+        "skein256-56" => Some(0xb307),
+        "skein256-64" => Some(0xb308),
+        "skein256-72" => Some(0xb309),
+        "skein256-80" => Some(0xb30a),
+        "skein256-88" => Some(0xb30b),
+        "skein256-96" => Some(0xb30c),
+        "skein256-104" => Some(0xb30d),
+        "skein256-112" => Some(0xb30e),
+        "skein256-120" => Some(0xb30f),
+        "skein256-128" => Some(0xb310),
+        "skein256-136" => Some(0xb311),
+        "skein256-144" => Some(0xb312),
+        "skein256-152" => Some(0xb313),
+        "skein256-160" => Some(0xb314),
+        "skein256-168" => Some(0xb315),
+        "skein256-176" => Some(0xb316),
+        "skein256-184" => Some(0xb317),
+        "skein256-192" => Some(0xb318),
+        "skein256-200" => Some(0xb319),
+        "skein256-208" => Some(0xb31a),
+        "skein256-216" => Some(0xb31b),
+        "skein256-224" => Some(0xb31c),
+        "skein256-232" => Some(0xb31d),
+        "skein256-240" => Some(0xb31e),
+        "skein256-248" => Some(0xb31f),
+        "skein256-256" => Some(0xb320),
+        "skein512-8" => Some(0xb321),
+        "skein512-16" => Some(0xb322),
+        "skein512-24" => Some(0xb323),
+        "skein512-32" => Some(0xb324),
+        "skein512-40" => Some(0xb325),
+        "skein512-48" => Some(0xb326),
+        "skein512-56" => Some(0xb327),
+        "skein512-64" => Some(0xb328),
+        "skein512-72" => Some(0xb329),
+        "skein512-80" => Some(0xb32a),
+        "skein512-88" => Some(0xb32b),
+        "skein512-96" => Some(0xb32c),
+        "skein512-104" => Some(0xb32d),
+        "skein512-112" => Some(0xb32e),
+        "skein512-120" => Some(0xb32f),
+        "skein512-128" => Some(0xb330),
+        "skein512-136" => Some(0xb331),
+        "skein512-144" => Some(0xb332),
+        "skein512-152" => Some(0xb333),
+        "skein512-160" => Some(0xb334),
+        "skein512-168" => Some(0xb335),
+        "skein512-176" => Some(0xb336),
+        "skein512-184" => Some(0xb337),
+        "skein512-192" => Some(0xb338),
+        "skein512-200" => Some(0xb339),
+        "skein512-208" => Some(0xb33a),
+        "skein512-216" => Some(0xb33b),
+        "skein512-224" => Some(0xb33c),
+        "skein512-232" => Some(0xb33d),
+        "skein512-240" => Some(0xb33e),
+        "skein512-248" => Some(0xb33f),
+        "skein512-256" => Some(0xb340),
+        "skein512-264" => Some(0xb341),
+        "skein512-272" => Some(0xb342),
+        "skein512-280" => Some(0xb343),
+        "skein512-288" => Some(0xb344),
+        "skein512-296" => Some(0xb345),
+        "skein512-304" => Some(0xb346),
+        "skein512-312" => Some(0xb347),
+        "skein512-320" => Some(0xb348),
+        "skein512-328" => Some(0xb349),
+        "skein512-336" => Some(0xb34a),
+        "skein512-344" => Some(0xb34b),
+        "skein512-352" => Some(0xb34c),
+        "skein512-360" => Some(0xb34d),
+        "skein512-368" => Some(0xb34e),
+        "skein512-376" => Some(0xb34f),
+        "skein512-384" => Some(0xb350),
+        "skein512-392" => Some(0xb351),
+        "skein512-400" => Some(0xb352),
+        "skein512-408" => Some(0xb353),
+        "skein512-416" => Some(0xb354),
+        "skein512-424" => Some(0xb355),
+        "skein512-432" => Some(0xb356),
+        "skein512-440" => Some(0xb357),
+        "skein512-448" => Some(0xb358),
+        "skein512-456" => Some(0xb359),
+        "skein512-464" => Some(0xb35a),
+        "skein512-472" => Some(0xb35b),
+        "skein512-480" => Some(0xb35c),
+        "skein512-488" => Some(0xb35d),
+        "skein512-496" => Some(0xb35e),
+        "skein512-504" => Some(0xb35f),
+        "skein512-512" => Some(0xb360),
+        "skein1024-8" => Some(0xb361),
+        "skein1024-16" => Some(0xb362),
+        "skein1024-24" => Some(0xb363),
+        "skein1024-32" => Some(0xb364),
+        "skein1024-40" => Some(0xb365),
+        "skein1024-48" => Some(0xb366),
+        "skein1024-56" => Some(0xb367),
+        "skein1024-64" => Some(0xb368),
+        "skein1024-72" => Some(0xb369),
+        "skein1024-80" => Some(0xb36a),
+        "skein1024-88" => Some(0xb36b),
+        "skein1024-96" => Some(0xb36c),
+        "skein1024-104" => Some(0xb36d),
+        "skein1024-112" => Some(0xb36e),
+        "skein1024-120" => Some(0xb36f),
+        "skein1024-128" => Some(0xb370),
+        "skein1024-136" => Some(0xb371),
+        "skein1024-144" => Some(0xb372),
+        "skein1024-152" => Some(0xb373),
+        "skein1024-160" => Some(0xb374),
+        "skein1024-168" => Some(0xb375),
+        "skein1024-176" => Some(0xb376),
+        "skein1024-184" => Some(0xb377),
+        "skein1024-192" => Some(0xb378),
+        "skein1024-200" => Some(0xb379),
+        "skein1024-208" => Some(0xb37a),
+        "skein1024-216" => Some(0xb37b),
+        "skein1024-224" => Some(0xb37c),
+        "skein1024-232" => Some(0xb37d),
+        "skein1024-240" => Some(0xb37e),
+        "skein1024-248" => Some(0xb37f),
+        "skein1024-256" => Some(0xb380),
+        "skein1024-264" => Some(0xb381),
+        "skein1024-272" => Some(0xb382),
+        "skein1024-280" => Some(0xb383),
+        "skein1024-288" => Some(0xb384),
+        "skein1024-296" => Some(0xb385),
+        "skein1024-304" => Some(0xb386),
+        "skein1024-312" => Some(0xb387),
+        "skein1024-320" => Some(0xb388),
+        "skein1024-328" => Some(0xb389),
+        "skein1024-336" => Some(0xb38a),
+        "skein1024-344" => Some(0xb38b),
+        "skein1024-352" => Some(0xb38c),
+        "skein1024-360" => Some(0xb38d),
+        "skein1024-368" => Some(0xb38e),
+        "skein1024-376" => Some(0xb38f),
+        "skein1024-384" => Some(0xb390),
+        "skein1024-392" => Some(0xb391),
+        "skein1024-400" => Some(0xb392),
+        "skein1024-408" => Some(0xb393),
+        "skein1024-416" => Some(0xb394),
+        "skein1024-424" => Some(0xb395),
+        "skein1024-432" => Some(0xb396),
+        "skein1024-440" => Some(0xb397),
+        "skein1024-448" => Some(0xb398),
+        "skein1024-456" => Some(0xb399),
+        "skein1024-464" => Some(0xb39a),
+        "skein1024-472" => Some(0xb39b),
+        "skein1024-480" => Some(0xb39c),
+        "skein1024-488" => Some(0xb39d),
+        "skein1024-496" => Some(0xb39e),
+        "skein1024-504" => Some(0xb39f),
+        "skein1024-512" => Some(0xb3a0),
+        "skein1024-520" => Some(0xb3a1),
+        "skein1024-528" => Some(0xb3a2),
+        "skein1024-536" => Some(0xb3a3),
+        "skein1024-544" => Some(0xb3a4),
+        "skein1024-552" => Some(0xb3a5),
+        "skein1024-560" => Some(0xb3a6),
+        "skein1024-568" => Some(0xb3a7),
+        "skein1024-576" => Some(0xb3a8),
+        "skein1024-584" => Some(0xb3a9),
+        "skein1024-592" => Some(0xb3aa),
+        "skein1024-600" => Some(0xb3ab),
+        "skein1024-608" => Some(0xb3ac),
+        "skein1024-616" => Some(0xb3ad),
+        "skein1024-624" => Some(0xb3ae),
+        "skein1024-632" => Some(0xb3af),
+        "skein1024-640" => Some(0xb3b0),
+        "skein1024-648" => Some(0xb3b1),
+        "skein1024-656" => Some(0xb3b2),
+        "skein1024-664" => Some(0xb3b3),
+        "skein1024-672" => Some(0xb3b4),
+        "skein1024-680" => Some(0xb3b5),
+        "skein1024-688" => Some(0xb3b6),
+        "skein1024-696" => Some(0xb3b7),
+        "skein1024-704" => Some(0xb3b8),
+        "skein1024-712" => Some(0xb3b9),
+        "skein1024-720" => Some(0xb3ba),
+        "skein1024-728" => Some(0xb3bb),
+        "skein1024-736" => Some(0xb3bc),
+        "skein1024-744" => Some(0xb3bd),
+        "skein1024-752" => Some(0xb3be),
+        "skein1024-760" => Some(0xb3bf),
+        "skein1024-768" => Some(0xb3c0),
+        "skein1024-776" => Some(0xb3c1),
+        "skein1024-784" => Some(0xb3c2),
+        "skein1024-792" => Some(0xb3c3),
+        "skein1024-800" => Some(0xb3c4),
+        "skein1024-808" => Some(0xb3c5),
+        "skein1024-816" => Some(0xb3c6),
+        "skein1024-824" => Some(0xb3c7),
+        "skein1024-832" => Some(0xb3c8),
+        "skein1024-840" => Some(0xb3c9),
+        "skein1024-848" => Some(0xb3ca),
+        "skein1024-856" => Some(0xb3cb),
+        "skein1024-864" => Some(0xb3cc),
+        "skein1024-872" => Some(0xb3cd),
+        "skein1024-880" => Some(0xb3ce),
+        "skein1024-888" => Some(0xb3cf),
+        "skein1024-896" => Some(0xb3d0),
+        "skein1024-904" => Some(0xb3d1),
+        "skein1024-912" => Some(0xb3d2),
+        "skein1024-920" => Some(0xb3d3),
+        "skein1024-928" => Some(0xb3d4),
+        "skein1024-936" => Some(0xb3d5),
+        "skein1024-944" => Some(0xb3d6),
+        "skein1024-952" => Some(0xb3d7),
+        "skein1024-960" => Some(0xb3d8),
+        "skein1024-968" => Some(0xb3d9),
+        "skein1024-976" => Some(0xb3da),
+        "skein1024-984" => Some(0xb3db),
+        "skein1024-992" => Some(0xb3dc),
+        "skein1024-1000" => Some(0xb3dd),
+        "skein1024-1008" => Some(0xb3de),
+        "skein1024-1016" => Some(0xb3df),
+        "skein1024-1024" => Some(0xb3e0),
+        "xxh-32" => Some(0xb3e1),
+        "xxh-64" => Some(0xb3e2),
+        "xxh3-64" => Some(0xb3e3),
+        "xxh3-128" => Some(0xb3e4),
+        "poseidon-bls12_381-a2-fc1" => Some(0xb401),
+        "poseidon-bls12_381-a2-fc1-sc" => Some(0xb402),
+        "urdca-2015-canon" => Some(0xb403),
+        "ssz" => Some(0xb501),
+        "ssz-sha2-256-bmt" => Some(0xb502),
+        "json-jcs" => Some(0xb601),
+        "iscc" => Some(0xcc01),
+        "zeroxcert-imprint-256" => Some(0xce11),
+        "varsig" => Some(0xd000),
+        "es256k" => Some(0xd0e7),
+        "bls-12381-g1-sig" => Some(0xd0ea),
+        "bls-12381-g2-sig" => Some(0xd0eb),
+        "eddsa" => Some(0xd0ed),
+        "eip-191" => Some(0xd191),
+        "fil-commitment-unsealed" => Some(0xf101),
+        "fil-commitment-sealed" => Some(0xf102),
+        "plaintextv2" => Some(0x706c61),
+        "holochain-adr-v0" => Some(0x807124),
+        "holochain-adr-v1" => Some(0x817124),
+        "holochain-key-v0" => Some(0x947124),
+        "holochain-key-v1" => Some(0x957124),
+        "holochain-sig-v0" => Some(0xa27124),
+        "holochain-sig-v1" => Some(0xa37124),
+        "skynet-ns" => Some(0xb19910),
+        "arweave-ns" => Some(0xb29910),
+        "subspace-ns" => Some(0xb39910),
+        "kumandra-ns" => Some(0xb49910),
+        "es256" => Some(0xd01200),
+        "es284" => Some(0xd01201),
+        "es512" => Some(0xd01202),
+        "rs256" => Some(0xd01205),
+        _ => None,
     }
 }
 
-pub fn to_type(val: u64) -> String {
+pub fn try_to_type(val: u64) -> Option<&'static str> {
     match val {
-        0x00 => "identity",
-        0x01 => "cidv1",
-        0x02 => "cidv2",
-        0x03 => "cidv3",
-        0x04 => "ip4",
-        0x06 => "tcp",
-        0x11 => "sha1",
-        0x12 => "sha2-256",
-        0x13 => "sha2-512",
-        0x14 => "sha3-512",
-        0x15 => "sha3-384",
-        0x16 => "sha3-256",
-        0x17 => "sha3-224",
-        0x18 => "shake-128",
-        0x19 => "shake-256",
-        0x1a => "keccak-224",
-        0x1b => "keccak-256",
-        0x1c => "keccak-384",
-        0x1d => "keccak-512",
-        0x1e => "blake3",
-        0x20 => "sha2-384",
-        0x21 => "dccp",
-        0x22 => "murmur3-x64-64",
-        0x23 => "murmur3-32",
-        0x29 => "ip6",
-        0x2a => "ip6zone",
-        0x2b => "ipcidr",
-        0x2f => "path",
-        0x30 => "multicodec",
-        0x31 => "multihash",
-        0x32 => "multiaddr",
-        0x33 => "multibase",
-        0x35 => "dns",
-        0x36 => "dns4",
-        0x37 => "dns6",
-        0x38 => "dnsaddr",
-        0x50 => "protobuf",
-        0x51 => "cbor",
-        0x55 => "raw",
-        0x56 => "dbl-sha2-256",
-        0x60 => "rlp",
-        0x63 => "bencode",
-        0x70 => "dag-pb",
-        0x71 => "dag-cbor",
-        0x72 => "libp2p-key",
-        0x78 => "git-raw",
-        0x7b => "torrent-info",
-        0x7c => "torrent-file",
-        0x81 => "leofcoin-block",
-        0x82 => "leofcoin-tx",
-        0x83 => "leofcoin-pr",
-        0x84 => "sctp",
-        0x85 => "dag-jose",
-        0x86 => "dag-cose",
-        0x90 => "eth-block",
-        0x91 => "eth-block-list",
-        0x92 => "eth-tx-trie",
-        0x93 => "eth-tx",
-        0x94 => "eth-tx-receipt-trie",
-        0x95 => "eth-tx-receipt",
-        0x96 => "eth-state-trie",
-        0x97 => "eth-account-snapshot",
-        0x98 => "eth-storage-trie",
-        0x99 => "eth-receipt-log-trie",
-        0x9a => "eth-reciept-log",
-        0xa0 => "aes-128",
-        0xa1 => "aes-192",
-        0xa2 => "aes-256",
-        0xa3 => "chacha-128",
-        0xa4 => "chacha-256",
-        0xb0 => "bitcoin-block",
-        0xb1 => "bitcoin-tx",
-        0xb2 => "bitcoin-witness-commitment",
-        0xc0 => "zcash-block",
-        0xc1 => "zcash-tx",
-        0xca => "caip-50",
-        0xce => "streamid",
-        0xd0 => "stellar-block",
-        0xd1 => "stellar-tx",
-        0xd4 => "md4",
-        0xd5 => "md5",
-        0xe0 => "decred-block",
-        0xe1 => "decred-tx",
-        0xe2 => "ipld",
-        0xe3 => "ipfs",
-        0xe4 => "swarm",
-        0xe5 => "ipns",
-        0xe6 => "zeronet",
-        0xe7 => "secp256k1-pub",
-        0xe8 => "dnslink",
-        0xea => "bls12_381-g1-pub",
-        0xeb => "bls12_381-g2-pub",
-        0xec => "x25519-pub",
-        0xed => "ed25519-pub",
-        0xee => "bls12_381-g1g2-pub",
-        0xf0 => "dash-block",
-        0xf1 => "dash-tx",
-        0xfa => "swarm-manifest",
-        0xfb => "swarm-feed",
-        0xfc => "beeson",
-        0x0111 => "udp",
-        0x0113 => "p2p-webrtc-star",
-        0x0114 => "p2p-webrtc-direct",
-        0x0115 => "p2p-stardust",
-        0x0118 => "webrtc",
-        0x0122 => "p2p-circuit",
-        0x0129 => "dag-json",
-        0x012d => "udt",
-        0x012e => "utp",
-        0x0132 => "crc32",
-        0x0164 => "crc64-ecma",
-        0x0190 => "unix",
-        0x0196 => "thread",
-        0x01a5 => "p2p",
-        0x01bb => "https",
-        0x01bc => "onion",
-        0x01bd => "onion3",
-        0x01be => "garlic64",
-        0x01bf => "garlic32",
-        0x01c0 => "tls",
-        0x01c1 => "sni",
-        0x01c6 => "noise",
-        0x01cc => "quic",
-        0x01cd => "quic-v1",
-        0x01d1 => "webtransport",
-        0x01d2 => "certhash",
-        0x01dd => "ws",
-        0x01de => "wss",
-        0x01df => "p2p-websocket-star",
-        0x01e0 => "http",
-        0x01f0 => "swhid-1-snp",
-        0x0200 => "json",
-        0x0201 => "messagepack",
-        0x0202 => "car",
-        0x0301 => "libp2p-peer-record",
-        0x0302 => "libp2p-relay-rsvp",
-        0x0309 => "memorytransport",
-        0x0400 => "car-index-sorted",
-        0x0401 => "car-multihash-index-sorted",
-        0x0900 => "transport-bitswap",
-        0x0910 => "transport-graphsync-filecoinv1",
-        0x0d1d => "multidid",
-        0x1012 => "sha2-256-trunc254-padded",
-        0x1013 => "sha2-224",
-        0x1014 => "sha2-512-224",
-        0x1015 => "sha2-512-256",
-        0x1022 => "murmur3-x64-128",
-        0x1052 => "ripemd-128",
-        0x1053 => "ripemd-160",
-        0x1054 => "ripemd-256",
-        0x1055 => "ripemd-320",
-        0x1100 => "x11",
-        0x1200 => "p256-pub",
-        0x1201 => "p384-pub",
-        0x1202 => "p521-pub",
-        0x1203 => "ed448-pub",
-        0x1204 => "x448-pub",
-        0x1205 => "rsa-pub",
-        0x1206 => "sm2-pub",
-        0x1300 => "ed25519-priv",
-        0x1301 => "secp256k1-priv",
-        0x1302 => "x25519-priv",
-        0x1305 => "rsa-priv",
-        0x1d01 => "kangarootwelve",
-        0x3f42 => "silverpine",
-        0x534d => "sm3-256",
-        0xb201 => "blake2b-8",
-        0xb202 => "blake2b-16",
-        0xb203 => "blake2b-24",
-        0xb204 => "blake2b-32",
-        0xb205 => "blake2b-40",
-        0xb206 => "blake2b-48",
-        0xb207 => "blake2b-56",
-        0xb208 => "blake2b-64",
-        0xb209 => "blake2b-72",
-        0xb20a => "blake2b-80",
-        0xb20b => "blake2b-88",
-        0xb20c => "blake2b-96",
-        0xb20d => "blake2b-104",
-        0xb20e => "blake2b-112",
-        0xb20f => "blake2b-120",
-        0xb210 => "blake2b-128",
-        0xb211 => "blake2b-136",
-        0xb212 => "blake2b-144",
-        0xb213 => "blake2b-152",
-        0xb214 => "blake2b-160",
-        0xb215 => "blake2b-168",
-        0xb216 => "blake2b-176",
-        0xb217 => "blake2b-184",
-        0xb218 => "blake2b-192",
-        0xb219 => "blake2b-200",
-        0xb21a => "blake2b-208",
-        0xb21b => "blake2b-216",
-        0xb21c => "blake2b-224",
-        0xb21d => "blake2b-232",
-        0xb21e => "blake2b-240",
-        0xb21f => "blake2b-248",
-        0xb220 => "blake2b-256",
-        0xb221 => "blake2b-264",
-        0xb222 => "blake2b-272",
-        0xb223 => "blake2b-280",
-        0xb224 => "blake2b-288",
-        0xb225 => "blake2b-296",
-        0xb226 => "blake2b-304",
-        0xb227 => "blake2b-312",
-        0xb228 => "blake2b-320",
-        0xb229 => "blake2b-328",
-        0xb22a => "blake2b-336",
-        0xb22b => "blake2b-344",
-        0xb22c => "blake2b-352",
-        0xb22d => "blake2b-360",
-        0xb22e => "blake2b-368",
-        0xb22f => "blake2b-376",
-        0xb230 => "blake2b-384",
-        0xb231 => "blake2b-392",
-        0xb232 => "blake2b-400",
-        0xb233 => "blake2b-408",
-        0xb234 => "blake2b-416",
-        0xb235 => "blake2b-424",
-        0xb236 => "blake2b-432",
-        0xb237 => "blake2b-440",
-        0xb238 => "blake2b-448",
-        0xb239 => "blake2b-456",
-        0xb23a => "blake2b-464",
-        0xb23b => "blake2b-472",
-        0xb23c => "blake2b-480",
-        0xb23d => "blake2b-488",
-        0xb23e => "blake2b-496",
-        0xb23f => "blake2b-504",
-        0xb240 => "blake2b-512",
-        0xb241 => "blake2s-8",
-        0xb242 => "blake2s-16",
-        0xb243 => "blake2s-24",
-        0xb244 => "blake2s-32",
-        0xb245 => "blake2s-40",
-        0xb246 => "blake2s-48",
-        0xb247 => "blake2s-56",
-        0xb248 => "blake2s-64",
-        0xb249 => "blake2s-72",
-        0xb24a => "blake2s-80",
-        0xb24b => "blake2s-88",
-        0xb24c => "blake2s-96",
-        0xb24d => "blake2s-104",
-        0xb24e => "blake2s-112",
-        0xb24f => "blake2s-120",
-        0xb250 => "blake2s-128",
-        0xb251 => "blake2s-136",
-        0xb252 => "blake2s-144",
-        0xb253 => "blake2s-152",
-        0xb254 => "blake2s-160",
-        0xb255 => "blake2s-168",
-        0xb256 => "blake2s-176",
-        0xb257 => "blake2s-184",
-        0xb258 => "blake2s-192",
-        0xb259 => "blake2s-200",
-        0xb25a => "blake2s-208",
-        0xb25b => "blake2s-216",
-        0xb25c => "blake2s-224",
-        0xb25d => "blake2s-232",
-        0xb25e => "blake2s-240",
-        0xb25f => "blake2s-248",
-        0xb260 => "blake2s-256",
-        0xb301 => "skein256-8",
-        0xb302 => "skein256-16",
-        0xb303 => "skein256-24",
-        0xb304 => "skein256-32",
-        0xb305 => "skein256-40",
-        0xb306 => "skein256-48",
-        0xb307 => "skein256-56",
-        0xb308 => "skein256-64",
-        0xb309 => "skein256-72",
-        0xb30a => "skein256-80",
-        0xb30b => "skein256-88",
-        0xb30c => "skein256-96",
-        0xb30d => "skein256-104",
-        0xb30e => "skein256-112",
-        0xb30f => "skein256-120",
-        0xb310 => "skein256-128",
-        0xb311 => "skein256-136",
-        0xb312 => "skein256-144",
-        0xb313 => "skein256-152",
-        0xb314 => "skein256-160",
-        0xb315 => "skein256-168",
-        0xb316 => "skein256-176",
-        0xb317 => "skein256-184",
-        0xb318 => "skein256-192",
-        0xb319 => "skein256-200",
-        0xb31a => "skein256-208",
-        0xb31b => "skein256-216",
-        0xb31c => "skein256-224",
-        0xb31d => "skein256-232",
-        0xb31e => "skein256-240",
-        0xb31f => "skein256-248",
-        0xb320 => "skein256-256",
-        0xb321 => "skein512-8",
-        0xb322 => "skein512-16",
-        0xb323 => "skein512-24",
-        0xb324 => "skein512-32",
-        0xb325 => "skein512-40",
-        0xb326 => "skein512-48",
-        0xb327 => "skein512-56",
-        0xb328 => "skein512-64",
-        0xb329 => "skein512-72",
-        0xb32a => "skein512-80",
-        0xb32b => "skein512-88",
-        0xb32c => "skein512-96",
-        0xb32d => "skein512-104",
-        0xb32e => "skein512-112",
-        0xb32f => "skein512-120",
-        0xb330 => "skein512-128",
-        0xb331 => "skein512-136",
-        0xb332 => "skein512-144",
-        0xb333 => "skein512-152",
-        0xb334 => "skein512-160",
-        0xb335 => "skein512-168",
-        0xb336 => "skein512-176",
-        0xb337 => "skein512-184",
-        0xb338 => "skein512-192",
-        0xb339 => "skein512-200",
-        0xb33a => "skein512-208",
-        0xb33b => "skein512-216",
-        0xb33c => "skein512-224",
-        0xb33d => "skein512-232",
-        0xb33e => "skein512-240",
-        0xb33f => "skein512-248",
-        0xb340 => "skein512-256",
-        0xb341 => "skein512-264",
-        0xb342 => "skein512-272",
-        0xb343 => "skein512-280",
-        0xb344 => "skein512-288",
-        0xb345 => "skein512-296",
-        0xb346 => "skein512-304",
-        0xb347 => "skein512-312",
-        0xb348 => "skein512-320",
-        0xb349 => "skein512-328",
-        0xb34a => "skein512-336",
-        0xb34b => "skein512-344",
-        0xb34c => "skein512-352",
-        0xb34d => "skein512-360",
-        0xb34e => "skein512-368",
-        0xb34f => "skein512-376",
-        0xb350 => "skein512-384",
-        0xb351 => "skein512-392",
-        0xb352 => "skein512-400",
-        0xb353 => "skein512-408",
-        0xb354 => "skein512-416",
-        0xb355 => "skein512-424",
-        0xb356 => "skein512-432",
-        0xb357 => "skein512-440",
-        0xb358 => "skein512-448",
-        0xb359 => "skein512-456",
-        0xb35a => "skein512-464",
-        0xb35b => "skein512-472",
-        0xb35c => "skein512-480",
-        0xb35d => "skein512-488",
-        0xb35e => "skein512-496",
-        0xb35f => "skein512-504",
-        0xb360 => "skein512-512",
-        0xb361 => "skein1024-8",
-        0xb362 => "skein1024-16",
-        0xb363 => "skein1024-24",
-        0xb364 => "skein1024-32",
-        0xb365 => "skein1024-40",
-        0xb366 => "skein1024-48",
-        0xb367 => "skein1024-56",
-        0xb368 => "skein1024-64",
-        0xb369 => "skein1024-72",
-        0xb36a => "skein1024-80",
-        0xb36b => "skein1024-88",
-        0xb36c => "skein1024-96",
-        0xb36d => "skein1024-104",
-        0xb36e => "skein1024-112",
-        0xb36f => "skein1024-120",
-        0xb370 => "skein1024-128",
-        0xb371 => "skein1024-136",
-        0xb372 => "skein1024-144",
-        0xb373 => "skein1024-152",
-        0xb374 => "skein1024-160",
-        0xb375 => "skein1024-168",
-        0xb376 => "skein1024-176",
-        0xb377 => "skein1024-184",
-        0xb378 => "skein1024-192",
-        0xb379 => "skein1024-200",
-        0xb37a => "skein1024-208",
-        0xb37b => "skein1024-216",
-        0xb37c => "skein1024-224",
-        0xb37d => "skein1024-232",
-        0xb37e => "skein1024-240",
-        0xb37f => "skein1024-248",
-        0xb380 => "skein1024-256",
-        0xb381 => "skein1024-264",
-        0xb382 => "skein1024-272",
-        0xb383 => "skein1024-280",
-        0xb384 => "skein1024-288",
-        0xb385 => "skein1024-296",
-        0xb386 => "skein1024-304",
-        0xb387 => "skein1024-312",
-        0xb388 => "skein1024-320",
-        0xb389 => "skein1024-328",
-        0xb38a => "skein1024-336",
-        0xb38b => "skein1024-344",
-        0xb38c => "skein1024-352",
-        0xb38d => "skein1024-360",
-        0xb38e => "skein1024-368",
-        0xb38f => "skein1024-376",
-        0xb390 => "skein1024-384",
-        0xb391 => "skein1024-392",
-        0xb392 => "skein1024-400",
-        0xb393 => "skein1024-408",
-        0xb394 => "skein1024-416",
-        0xb395 => "skein1024-424",
-        0xb396 => "skein1024-432",
-        0xb397 => "skein1024-440",
-        0xb398 => "skein1024-448",
-        0xb399 => "skein1024-456",
-        0xb39a => "skein1024-464",
-        0xb39b => "skein1024-472",
-        0xb39c => "skein1024-480",
-        0xb39d => "skein1024-488",
-        0xb39e => "skein1024-496",
-        0xb39f => "skein1024-504",
-        0xb3a0 => "skein1024-512",
-        0xb3a1 => "skein1024-520",
-        0xb3a2 => "skein1024-528",
-        0xb3a3 => "skein1024-536",
-        0xb3a4 => "skein1024-544",
-        0xb3a5 => "skein1024-552",
-        0xb3a6 => "skein1024-560",
-        0xb3a7 => "skein1024-568",
-        0xb3a8 => "skein1024-576",
-        0xb3a9 => "skein1024-584",
-        0xb3aa => "skein1024-592",
-        0xb3ab => "skein1024-600",
-        0xb3ac => "skein1024-608",
-        0xb3ad => "skein1024-616",
-        0xb3ae => "skein1024-624",
-        0xb3af => "skein1024-632",
-        0xb3b0 => "skein1024-640",
-        0xb3b1 => "skein1024-648",
-        0xb3b2 => "skein1024-656",
-        0xb3b3 => "skein1024-664",
-        0xb3b4 => "skein1024-672",
-        0xb3b5 => "skein1024-680",
-        0xb3b6 => "skein1024-688",
-        0xb3b7 => "skein1024-696",
-        0xb3b8 => "skein1024-704",
-        0xb3b9 => "skein1024-712",
-        0xb3ba => "skein1024-720",
-        0xb3bb => "skein1024-728",
-        0xb3bc => "skein1024-736",
-        0xb3bd => "skein1024-744",
-        0xb3be => "skein1024-752",
-        0xb3bf => "skein1024-760",
-        0xb3c0 => "skein1024-768",
-        0xb3c1 => "skein1024-776",
-        0xb3c2 => "skein1024-784",
-        0xb3c3 => "skein1024-792",
-        0xb3c4 => "skein1024-800",
-        0xb3c5 => "skein1024-808",
-        0xb3c6 => "skein1024-816",
-        0xb3c7 => "skein1024-824",
-        0xb3c8 => "skein1024-832",
-        0xb3c9 => "skein1024-840",
-        0xb3ca => "skein1024-848",
-        0xb3cb => "skein1024-856",
-        0xb3cc => "skein1024-864",
-        0xb3cd => "skein1024-872",
-        0xb3ce => "skein1024-880",
-        0xb3cf => "skein1024-888",
-        0xb3d0 => "skein1024-896",
-        0xb3d1 => "skein1024-904",
-        0xb3d2 => "skein1024-912",
-        0xb3d3 => "skein1024-920",
-        0xb3d4 => "skein1024-928",
-        0xb3d5 => "skein1024-936",
-        0xb3d6 => "skein1024-944",
-        0xb3d7 => "skein1024-952",
-        0xb3d8 => "skein1024-960",
-        0xb3d9 => "skein1024-968",
-        0xb3da => "skein1024-976",
-        0xb3db => "skein1024-984",
-        0xb3dc => "skein1024-992",
-        0xb3dd => "skein1024-1000",
-        0xb3de => "skein1024-1008",
-        0xb3df => "skein1024-1016",
-        0xb3e0 => "skein1024-1024",
-        0xb3e1 => "xxh-32",
-        0xb3e2 => "xxh-64",
-        0xb3e3 => "xxh3-64",
-        0xb3e4 => "xxh3-128",
-        0xb401 => "poseidon-bls12_381-a2-fc1",
-        0xb402 => "poseidon-bls12_381-a2-fc1-sc",
-        0xb403 => "urdca-2015-canon",
-        0xb501 => "ssz",
-        0xb502 => "ssz-sha2-256-bmt",
-        0xb601 => "json-jcs",
-        0xcc01 => "iscc",
-        0xce11 => "zeroxcert-imprint-256",
-        0xd000 => "varsig",
-        0xd0e7 => "es256k",
-        0xd0ea => "bls-12381-g1-sig",
-        0xd0eb => "bls-12381-g2-sig",
-        0xd0ed => "eddsa",
-        0xd191 => "eip-191",
-        0xf101 => "fil-commitment-unsealed",
-        0xf102 => "fil-commitment-sealed",
-        0x706c61 => "plaintextv2",
-        0x807124 => "holochain-adr-v0",
-        0x817124 => "holochain-adr-v1",
-        0x947124 => "holochain-key-v0",
-        0x957124 => "holochain-key-v1",
-        0xa27124 => "holochain-sig-v0",
-        0xa37124 => "holochain-sig-v1",
-        0xb19910 => "skynet-ns",
-        0xb29910 => "arweave-ns",
-        0xb39910 => "subspace-ns",
-        0xb49910 => "kumandra-ns",
-        0xd01200 => "es256",
-        0xd01201 => "es284",
-        0xd01202 => "es512",
-        0xd01205 => "rs256",
-        _ => unreachable!(),
+        0x00 => Some("identity"),
+        0x01 => Some("cidv1"),
+        0x02 => Some("cidv2"),
+        0x03 => Some("cidv3"),
+        0x04 => Some("ip4"),
+        0x06 => Some("tcp"),
+        0x11 => Some("sha1"),
+        0x12 => Some("sha2-256"),
+        0x13 => Some("sha2-512"),
+        0x14 => Some("sha3-512"),
+        0x15 => Some("sha3-384"),
+        0x16 => Some("sha3-256"),
+        0x17 => Some("sha3-224"),
+        0x18 => Some("shake-128"),
+        0x19 => Some("shake-256"),
+        0x1a => Some("keccak-224"),
+        0x1b => Some("keccak-256"),
+        0x1c => Some("keccak-384"),
+        0x1d => Some("keccak-512"),
+        0x1e => Some("blake3"),
+        0x20 => Some("sha2-384"),
+        0x21 => Some("dccp"),
+        0x22 => Some("murmur3-x64-64"),
+        0x23 => Some("murmur3-32"),
+        0x29 => Some("ip6"),
+        0x2a => Some("ip6zone"),
+        0x2b => Some("ipcidr"),
+        0x2f => Some("path"),
+        0x30 => Some("multicodec"),
+        0x31 => Some("multihash"),
+        0x32 => Some("multiaddr"),
+        0x33 => Some("multibase"),
+        0x35 => Some("dns"),
+        0x36 => Some("dns4"),
+        0x37 => Some("dns6"),
+        0x38 => Some("dnsaddr"),
+        0x50 => Some("protobuf"),
+        0x51 => Some("cbor"),
+        0x55 => Some("raw"),
+        0x56 => Some("dbl-sha2-256"),
+        0x60 => Some("rlp"),
+        0x63 => Some("bencode"),
+        0x70 => Some("dag-pb"),
+        0x71 => Some("dag-cbor"),
+        0x72 => Some("libp2p-key"),
+        0x78 => Some("git-raw"),
+        0x7b => Some("torrent-info"),
+        0x7c => Some("torrent-file"),
+        0x81 => Some("leofcoin-block"),
+        0x82 => Some("leofcoin-tx"),
+        0x83 => Some("leofcoin-pr"),
+        0x84 => Some("sctp"),
+        0x85 => Some("dag-jose"),
+        0x86 => Some("dag-cose"),
+        0x90 => Some("eth-block"),
+        0x91 => Some("eth-block-list"),
+        0x92 => Some("eth-tx-trie"),
+        0x93 => Some("eth-tx"),
+        0x94 => Some("eth-tx-receipt-trie"),
+        0x95 => Some("eth-tx-receipt"),
+        0x96 => Some("eth-state-trie"),
+        0x97 => Some("eth-account-snapshot"),
+        0x98 => Some("eth-storage-trie"),
+        0x99 => Some("eth-receipt-log-trie"),
+        0x9a => Some("eth-reciept-log"),
+        0xa0 => Some("aes-128"),
+        0xa1 => Some("aes-192"),
+        0xa2 => Some("aes-256"),
+        0xa3 => Some("chacha-128"),
+        0xa4 => Some("chacha-256"),
+        0xb0 => Some("bitcoin-block"),
+        0xb1 => Some("bitcoin-tx"),
+        0xb2 => Some("bitcoin-witness-commitment"),
+        0xc0 => Some("zcash-block"),
+        0xc1 => Some("zcash-tx"),
+        0xca => Some("caip-50"),
+        0xce => Some("streamid"),
+        0xd0 => Some("stellar-block"),
+        0xd1 => Some("stellar-tx"),
+        0xd4 => Some("md4"),
+        0xd5 => Some("md5"),
+        0xe0 => Some("decred-block"),
+        0xe1 => Some("decred-tx"),
+        0xe2 => Some("ipld"),
+        0xe3 => Some("ipfs"),
+        0xe4 => Some("swarm"),
+        0xe5 => Some("ipns"),
+        0xe6 => Some("zeronet"),
+        0xe7 => Some("secp256k1-pub"),
+        0xe8 => Some("dnslink"),
+        0xea => Some("bls12_381-g1-pub"),
+        0xeb => Some("bls12_381-g2-pub"),
+        0xec => Some("x25519-pub"),
+        0xed => Some("ed25519-pub"),
+        0xee => Some("bls12_381-g1g2-pub"),
+        0xf0 => Some("dash-block"),
+        0xf1 => Some("dash-tx"),
+        0xfa => Some("swarm-manifest"),
+        0xfb => Some("swarm-feed"),
+        0xfc => Some("beeson"),
+        0x0111 => Some("udp"),
+        0x0113 => Some("p2p-webrtc-star"),
+        0x0114 => Some("p2p-webrtc-direct"),
+        0x0115 => Some("p2p-stardust"),
+        0x0118 => Some("webrtc"),
+        0x0122 => Some("p2p-circuit"),
+        0x0129 => Some("dag-json"),
+        0x012d => Some("udt"),
+        0x012e => Some("utp"),
+        0x0132 => Some("crc32"),
+        0x0164 => Some("crc64-ecma"),
+        0x0190 => Some("unix"),
+        0x0196 => Some("thread"),
+        0x01a5 => Some("p2p"),
+        0x01bb => Some("https"),
+        0x01bc => Some("onion"),
+        0x01bd => Some("onion3"),
+        0x01be => Some("garlic64"),
+        0x01bf => Some("garlic32"),
+        0x01c0 => Some("tls"),
+        0x01c1 => Some("sni"),
+        0x01c6 => Some("noise"),
+        0x01cc => Some("quic"),
+        0x01cd => Some("quic-v1"),
+        0x01d1 => Some("webtransport"),
+        0x01d2 => Some("certhash"),
+        0x01dd => Some("ws"),
+        0x01de => Some("wss"),
+        0x01df => Some("p2p-websocket-star"),
+        0x01e0 => Some("http"),
+        0x01f0 => Some("swhid-1-snp"),
+        0x0200 => Some("json"),
+        0x0201 => Some("messagepack"),
+        0x0202 => Some("car"),
+        0x0301 => Some("libp2p-peer-record"),
+        0x0302 => Some("libp2p-relay-rsvp"),
+        0x0309 => Some("memorytransport"),
+        0x0400 => Some("car-index-sorted"),
+        0x0401 => Some("car-multihash-index-sorted"),
+        0x0900 => Some("transport-bitswap"),
+        0x0910 => Some("transport-graphsync-filecoinv1"),
+        0x0d1d => Some("multidid"),
+        0x1012 => Some("sha2-256-trunc254-padded"),
+        0x1013 => Some("sha2-224"),
+        0x1014 => Some("sha2-512-224"),
+        0x1015 => Some("sha2-512-256"),
+        0x1022 => Some("murmur3-x64-128"),
+        0x1052 => Some("ripemd-128"),
+        0x1053 => Some("ripemd-160"),
+        0x1054 => Some("ripemd-256"),
+        0x1055 => Some("ripemd-320"),
+        0x1100 => Some("x11"),
+        0x1200 => Some("p256-pub"),
+        0x1201 => Some("p384-pub"),
+        0x1202 => Some("p521-pub"),
+        0x1203 => Some("ed448-pub"),
+        0x1204 => Some("x448-pub"),
+        0x1205 => Some("rsa-pub"),
+        0x1206 => Some("sm2-pub"),
+        0x1300 => Some("ed25519-priv"),
+        0x1301 => Some("secp256k1-priv"),
+        0x1302 => Some("x25519-priv"),
+        0x1305 => Some("rsa-priv"),
+        0x1d01 => Some("kangarootwelve"),
+        0x3f42 => Some("silverpine"),
+        0x534d => Some("sm3-256"),
+        0xb201 => Some("blake2b-8"),
+        0xb202 => Some("blake2b-16"),
+        0xb203 => Some("blake2b-24"),
+        0xb204 => Some("blake2b-32"),
+        0xb205 => Some("blake2b-40"),
+        0xb206 => Some("blake2b-48"),
+        0xb207 => Some("blake2b-56"),
+        0xb208 => Some("blake2b-64"),
+        0xb209 => Some("blake2b-72"),
+        0xb20a => Some("blake2b-80"),
+        0xb20b => Some("blake2b-88"),
+        0xb20c => Some("blake2b-96"),
+        0xb20d => Some("blake2b-104"),
+        0xb20e => Some("blake2b-112"),
+        0xb20f => Some("blake2b-120"),
+        0xb210 => Some("blake2b-128"),
+        0xb211 => Some("blake2b-136"),
+        0xb212 => Some("blake2b-144"),
+        0xb213 => Some("blake2b-152"),
+        0xb214 => Some("blake2b-160"),
+        0xb215 => Some("blake2b-168"),
+        0xb216 => Some("blake2b-176"),
+        0xb217 => Some("blake2b-184"),
+        0xb218 => Some("blake2b-192"),
+        0xb219 => Some("blake2b-200"),
+        0xb21a => Some("blake2b-208"),
+        0xb21b => Some("blake2b-216"),
+        0xb21c => Some("blake2b-224"),
+        0xb21d => Some("blake2b-232"),
+        0xb21e => Some("blake2b-240"),
+        0xb21f => Some("blake2b-248"),
+        0xb220 => Some("blake2b-256"),
+        0xb221 => Some("blake2b-264"),
+        0xb222 => Some("blake2b-272"),
+        0xb223 => Some("blake2b-280"),
+        0xb224 => Some("blake2b-288"),
+        0xb225 => Some("blake2b-296"),
+        0xb226 => Some("blake2b-304"),
+        0xb227 => Some("blake2b-312"),
+        0xb228 => Some("blake2b-320"),
+        0xb229 => Some("blake2b-328"),
+        0xb22a => Some("blake2b-336"),
+        0xb22b => Some("blake2b-344"),
+        0xb22c => Some("blake2b-352"),
+        0xb22d => Some("blake2b-360"),
+        0xb22e => Some("blake2b-368"),
+        0xb22f => Some("blake2b-376"),
+        0xb230 => Some("blake2b-384"),
+        0xb231 => Some("blake2b-392"),
+        0xb232 => Some("blake2b-400"),
+        0xb233 => Some("blake2b-408"),
+        0xb234 => Some("blake2b-416"),
+        0xb235 => Some("blake2b-424"),
+        0xb236 => Some("blake2b-432"),
+        0xb237 => Some("blake2b-440"),
+        0xb238 => Some("blake2b-448"),
+        0xb239 => Some("blake2b-456"),
+        0xb23a => Some("blake2b-464"),
+        0xb23b => Some("blake2b-472"),
+        0xb23c => Some("blake2b-480"),
+        0xb23d => Some("blake2b-488"),
+        0xb23e => Some("blake2b-496"),
+        0xb23f => Some("blake2b-504"),
+        0xb240 => Some("blake2b-512"),
+        0xb241 => Some("blake2s-8"),
+        0xb242 => Some("blake2s-16"),
+        0xb243 => Some("blake2s-24"),
+        0xb244 => Some("blake2s-32"),
+        0xb245 => Some("blake2s-40"),
+        0xb246 => Some("blake2s-48"),
+        0xb247 => Some("blake2s-56"),
+        0xb248 => Some("blake2s-64"),
+        0xb249 => Some("blake2s-72"),
+        0xb24a => Some("blake2s-80"),
+        0xb24b => Some("blake2s-88"),
+        0xb24c => Some("blake2s-96"),
+        0xb24d => Some("blake2s-104"),
+        0xb24e => Some("blake2s-112"),
+        0xb24f => Some("blake2s-120"),
+        0xb250 => Some("blake2s-128"),
+        0xb251 => Some("blake2s-136"),
+        0xb252 => Some("blake2s-144"),
+        0xb253 => Some("blake2s-152"),
+        0xb254 => Some("blake2s-160"),
+        0xb255 => Some("blake2s-168"),
+        0xb256 => Some("blake2s-176"),
+        0xb257 => Some("blake2s-184"),
+        0xb258 => Some("blake2s-192"),
+        0xb259 => Some("blake2s-200"),
+        0xb25a => Some("blake2s-208"),
+        0xb25b => Some("blake2s-216"),
+        0xb25c => Some("blake2s-224"),
+        0xb25d => Some("blake2s-232"),
+        0xb25e => Some("blake2s-240"),
+        0xb25f => Some("blake2s-248"),
+        0xb260 => Some("blake2s-256"),
+        0xb301 => Some("skein256-8"),
+        0xb302 => Some("skein256-16"),
+        0xb303 => Some("skein256-24"),
+        0xb304 => Some("skein256-32"),
+        0xb305 => Some("skein256-40"),
+        0xb306 => Some("skein256-48"),
+        0xb307 => Some("skein256-56"),
+        0xb308 => Some("skein256-64"),
+        0xb309 => Some("skein256-72"),
+        0xb30a => Some("skein256-80"),
+        0xb30b => Some("skein256-88"),
+        0xb30c => Some("skein256-96"),
+        0xb30d => Some("skein256-104"),
+        0xb30e => Some("skein256-112"),
+        0xb30f => Some("skein256-120"),
+        0xb310 => Some("skein256-128"),
+        0xb311 => Some("skein256-136"),
+        0xb312 => Some("skein256-144"),
+        0xb313 => Some("skein256-152"),
+        0xb314 => Some("skein256-160"),
+        0xb315 => Some("skein256-168"),
+        0xb316 => Some("skein256-176"),
+        0xb317 => Some("skein256-184"),
+        0xb318 => Some("skein256-192"),
+        0xb319 => Some("skein256-200"),
+        0xb31a => Some("skein256-208"),
+        0xb31b => Some("skein256-216"),
+        0xb31c => Some("skein256-224"),
+        0xb31d => Some("skein256-232"),
+        0xb31e => Some("skein256-240"),
+        0xb31f => Some("skein256-248"),
+        0xb320 => Some("skein256-256"),
+        0xb321 => Some("skein512-8"),
+        0xb322 => Some("skein512-16"),
+        0xb323 => Some("skein512-24"),
+        0xb324 => Some("skein512-32"),
+        0xb325 => Some("skein512-40"),
+        0xb326 => Some("skein512-48"),
+        0xb327 => Some("skein512-56"),
+        0xb328 => Some("skein512-64"),
+        0xb329 => Some("skein512-72"),
+        0xb32a => Some("skein512-80"),
+        0xb32b => Some("skein512-88"),
+        0xb32c => Some("skein512-96"),
+        0xb32d => Some("skein512-104"),
+        0xb32e => Some("skein512-112"),
+        0xb32f => Some("skein512-120"),
+        0xb330 => Some("skein512-128"),
+        0xb331 => Some("skein512-136"),
+        0xb332 => Some("skein512-144"),
+        0xb333 => Some("skein512-152"),
+        0xb334 => Some("skein512-160"),
+        0xb335 => Some("skein512-168"),
+        0xb336 => Some("skein512-176"),
+        0xb337 => Some("skein512-184"),
+        0xb338 => Some("skein512-192"),
+        0xb339 => Some("skein512-200"),
+        0xb33a => Some("skein512-208"),
+        0xb33b => Some("skein512-216"),
+        0xb33c => Some("skein512-224"),
+        0xb33d => Some("skein512-232"),
+        0xb33e => Some("skein512-240"),
+        0xb33f => Some("skein512-248"),
+        0xb340 => Some("skein512-256"),
+        0xb341 => Some("skein512-264"),
+        0xb342 => Some("skein512-272"),
+        0xb343 => Some("skein512-280"),
+        0xb344 => Some("skein512-288"),
+        0xb345 => Some("skein512-296"),
+        0xb346 => Some("skein512-304"),
+        0xb347 => Some("skein512-312"),
+        0xb348 => Some("skein512-320"),
+        0xb349 => Some("skein512-328"),
+        0xb34a => Some("skein512-336"),
+        0xb34b => Some("skein512-344"),
+        0xb34c => Some("skein512-352"),
+        0xb34d => Some("skein512-360"),
+        0xb34e => Some("skein512-368"),
+        0xb34f => Some("skein512-376"),
+        0xb350 => Some("skein512-384"),
+        0xb351 => Some("skein512-392"),
+        0xb352 => Some("skein512-400"),
+        0xb353 => Some("skein512-408"),
+        0xb354 => Some("skein512-416"),
+        0xb355 => Some("skein512-424"),
+        0xb356 => Some("skein512-432"),
+        0xb357 => Some("skein512-440"),
+        0xb358 => Some("skein512-448"),
+        0xb359 => Some("skein512-456"),
+        0xb35a => Some("skein512-464"),
+        0xb35b => Some("skein512-472"),
+        0xb35c => Some("skein512-480"),
+        0xb35d => Some("skein512-488"),
+        0xb35e => Some("skein512-496"),
+        0xb35f => Some("skein512-504"),
+        0xb360 => Some("skein512-512"),
+        0xb361 => Some("skein1024-8"),
+        0xb362 => Some("skein1024-16"),
+        0xb363 => Some("skein1024-24"),
+        0xb364 => Some("skein1024-32"),
+        0xb365 => Some("skein1024-40"),
+        0xb366 => Some("skein1024-48"),
+        0xb367 => Some("skein1024-56"),
+        0xb368 => Some("skein1024-64"),
+        0xb369 => Some("skein1024-72"),
+        0xb36a => Some("skein1024-80"),
+        0xb36b => Some("skein1024-88"),
+        0xb36c => Some("skein1024-96"),
+        0xb36d => Some("skein1024-104"),
+        0xb36e => Some("skein1024-112"),
+        0xb36f => Some("skein1024-120"),
+        0xb370 => Some("skein1024-128"),
+        0xb371 => Some("skein1024-136"),
+        0xb372 => Some("skein1024-144"),
+        0xb373 => Some("skein1024-152"),
+        0xb374 => Some("skein1024-160"),
+        0xb375 => Some("skein1024-168"),
+        0xb376 => Some("skein1024-176"),
+        0xb377 => Some("skein1024-184"),
+        0xb378 => Some("skein1024-192"),
+        0xb379 => Some("skein1024-200"),
+        0xb37a => Some("skein1024-208"),
+        0xb37b => Some("skein1024-216"),
+        0xb37c => Some("skein1024-224"),
+        0xb37d => Some("skein1024-232"),
+        0xb37e => Some("skein1024-240"),
+        0xb37f => Some("skein1024-248"),
+        0xb380 => Some("skein1024-256"),
+        0xb381 => Some("skein1024-264"),
+        0xb382 => Some("skein1024-272"),
+        0xb383 => Some("skein1024-280"),
+        0xb384 => Some("skein1024-288"),
+        0xb385 => Some("skein1024-296"),
+        0xb386 => Some("skein1024-304"),
+        0xb387 => Some("skein1024-312"),
+        0xb388 => Some("skein1024-320"),
+        0xb389 => Some("skein1024-328"),
+        0xb38a => Some("skein1024-336"),
+        0xb38b => Some("skein1024-344"),
+        0xb38c => Some("skein1024-352"),
+        0xb38d => Some("skein1024-360"),
+        0xb38e => Some("skein1024-368"),
+        0xb38f => Some("skein1024-376"),
+        0xb390 => Some("skein1024-384"),
+        0xb391 => Some("skein1024-392"),
+        0xb392 => Some("skein1024-400"),
+        0xb393 => Some("skein1024-408"),
+        0xb394 => Some("skein1024-416"),
+        0xb395 => Some("skein1024-424"),
+        0xb396 => Some("skein1024-432"),
+        0xb397 => Some("skein1024-440"),
+        0xb398 => Some("skein1024-448"),
+        0xb399 => Some("skein1024-456"),
+        0xb39a => Some("skein1024-464"),
+        0xb39b => Some("skein1024-472"),
+        0xb39c => Some("skein1024-480"),
+        0xb39d => Some("skein1024-488"),
+        0xb39e => Some("skein1024-496"),
+        0xb39f => Some("skein1024-504"),
+        0xb3a0 => Some("skein1024-512"),
+        0xb3a1 => Some("skein1024-520"),
+        0xb3a2 => Some("skein1024-528"),
+        0xb3a3 => Some("skein1024-536"),
+        0xb3a4 => Some("skein1024-544"),
+        0xb3a5 => Some("skein1024-552"),
+        0xb3a6 => Some("skein1024-560"),
+        0xb3a7 => Some("skein1024-568"),
+        0xb3a8 => Some("skein1024-576"),
+        0xb3a9 => Some("skein1024-584"),
+        0xb3aa => Some("skein1024-592"),
+        0xb3ab => Some("skein1024-600"),
+        0xb3ac => Some("skein1024-608"),
+        0xb3ad => Some("skein1024-616"),
+        0xb3ae => Some("skein1024-624"),
+        0xb3af => Some("skein1024-632"),
+        0xb3b0 => Some("skein1024-640"),
+        0xb3b1 => Some("skein1024-648"),
+        0xb3b2 => Some("skein1024-656"),
+        0xb3b3 => Some("skein1024-664"),
+        0xb3b4 => Some("skein1024-672"),
+        0xb3b5 => Some("skein1024-680"),
+        0xb3b6 => Some("skein1024-688"),
+        0xb3b7 => Some("skein1024-696"),
+        0xb3b8 => Some("skein1024-704"),
+        0xb3b9 => Some("skein1024-712"),
+        0xb3ba => Some("skein1024-720"),
+        0xb3bb => Some("skein1024-728"),
+        0xb3bc => Some("skein1024-736"),
+        0xb3bd => Some("skein1024-744"),
+        0xb3be => Some("skein1024-752"),
+        0xb3bf => Some("skein1024-760"),
+        0xb3c0 => Some("skein1024-768"),
+        0xb3c1 => Some("skein1024-776"),
+        0xb3c2 => Some("skein1024-784"),
+        0xb3c3 => Some("skein1024-792"),
+        0xb3c4 => Some("skein1024-800"),
+        0xb3c5 => Some("skein1024-808"),
+        0xb3c6 => Some("skein1024-816"),
+        0xb3c7 => Some("skein1024-824"),
+        0xb3c8 => Some("skein1024-832"),
+        0xb3c9 => Some("skein1024-840"),
+        0xb3ca => Some("skein1024-848"),
+        0xb3cb => Some("skein1024-856"),
+        0xb3cc => Some("skein1024-864"),
+        0xb3cd => Some("skein1024-872"),
+        0xb3ce => Some("skein1024-880"),
+        0xb3cf => Some("skein1024-888"),
+        0xb3d0 => Some("skein1024-896"),
+        0xb3d1 => Some("skein1024-904"),
+        0xb3d2 => Some("skein1024-912"),
+        0xb3d3 => Some("skein1024-920"),
+        0xb3d4 => Some("skein1024-928"),
+        0xb3d5 => Some("skein1024-936"),
+        0xb3d6 => Some("skein1024-944"),
+        0xb3d7 => Some("skein1024-952"),
+        0xb3d8 => Some("skein1024-960"),
+        0xb3d9 => Some("skein1024-968"),
+        0xb3da => Some("skein1024-976"),
+        0xb3db => Some("skein1024-984"),
+        0xb3dc => Some("skein1024-992"),
+        0xb3dd => Some("skein1024-1000"),
+        0xb3de => Some("skein1024-1008"),
+        0xb3df => Some("skein1024-1016"),
+        0xb3e0 => Some("skein1024-1024"),
+        0xb3e1 => Some("xxh-32"),
+        0xb3e2 => Some("xxh-64"),
+        0xb3e3 => Some("xxh3-64"),
+        0xb3e4 => Some("xxh3-128"),
+        0xb401 => Some("poseidon-bls12_381-a2-fc1"),
+        0xb402 => Some("poseidon-bls12_381-a2-fc1-sc"),
+        0xb403 => Some("urdca-2015-canon"),
+        0xb501 => Some("ssz"),
+        0xb502 => Some("ssz-sha2-256-bmt"),
+        0xb601 => Some("json-jcs"),
+        0xcc01 => Some("iscc"),
+        0xce11 => Some("zeroxcert-imprint-256"),
+        0xd000 => Some("varsig"),
+        0xd0e7 => Some("es256k"),
+        0xd0ea => Some("bls-12381-g1-sig"),
+        0xd0eb => Some("bls-12381-g2-sig"),
+        0xd0ed => Some("eddsa"),
+        0xd191 => Some("eip-191"),
+        0xf101 => Some("fil-commitment-unsealed"),
+        0xf102 => Some("fil-commitment-sealed"),
+        0x706c61 => Some("plaintextv2"),
+        0x807124 => Some("holochain-adr-v0"),
+        0x817124 => Some("holochain-adr-v1"),
+        0x947124 => Some("holochain-key-v0"),
+        0x957124 => Some("holochain-key-v1"),
+        0xa27124 => Some("holochain-sig-v0"),
+        0xa37124 => Some("holochain-sig-v1"),
+        0xb19910 => Some("skynet-ns"),
+        0xb29910 => Some("arweave-ns"),
+        0xb39910 => Some("subspace-ns"),
+        0xb49910 => Some("kumandra-ns"),
+        0xd01200 => Some("es256"),
+        0xd01201 => Some("es284"),
+        0xd01202 => Some("es512"),
+        0xd01205 => Some("rs256"),
+        _ => None,
     }
-    .to_string()
 }
@@ -1,3 +1,11 @@
 mod generated_table;
 
 pub use generated_table::*;
+
+pub fn to_code(val: &str) -> u64 {
+    try_to_code(val).expect("unknown multicodec name")
+}
+
+pub fn to_type(val: u64) -> String {
+    try_to_type(val).expect("unknown multicodec code").to_string()
+}
@@ -0,0 +1,169 @@
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+use digest::DynDigest;
+use openssl::hash::Hasher;
+
+use crate::{blake2_len, message_digest, try_hash_len};
+
+const READ_BUFFER_SIZE: usize = 1 << 20;
+
+fn unsupported(hashtype: u64) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("unsupported hash function 0x{:x}", hashtype),
+    )
+}
+
+enum StreamHasher {
+    OpenSSL(Hasher),
+    Digest(Box<dyn DynDigest>),
+    Blake2b(blake2b_simd::State),
+    Blake2s(blake2s_simd::State),
+}
+
+impl StreamHasher {
+    fn new(hashtype: u64) -> io::Result<Self> {
+        let hasher = match hashtype {
+            0x11 | 0x12 | 0x13 | 0x14 | 0x15 | 0x16 | 0x17 | 0x18 | 0x19 | 0x20 => {
+                Self::OpenSSL(Hasher::new(message_digest(hashtype))?)
+            }
+
+            0x1052 => Self::Digest(Box::<ripemd::Ripemd128>::default()),
+            0x1053 => Self::Digest(Box::<ripemd::Ripemd160>::default()),
+            0x1054 => Self::Digest(Box::<ripemd::Ripemd256>::default()),
+            0x1055 => Self::Digest(Box::<ripemd::Ripemd320>::default()),
+
+            0x1a => Self::Digest(Box::<sha3::Keccak224>::default()),
+            0x1b => Self::Digest(Box::<sha3::Keccak256>::default()),
+            0x1c => Self::Digest(Box::<sha3::Keccak384>::default()),
+            0x1d => Self::Digest(Box::<sha3::Keccak512>::default()),
+
+            0xb201..=0xb240 => Self::Blake2b(
+                blake2b_simd::Params::new()
+                    .hash_length(blake2_len(hashtype))
+                    .to_state(),
+            ),
+            0xb241..=0xb260 => Self::Blake2s(
+                blake2s_simd::Params::new()
+                    .hash_length(blake2_len(hashtype))
+                    .to_state(),
+            ),
+
+            _ => return Err(unsupported(hashtype)),
+        };
+
+        Ok(hasher)
+    }
+
+    fn update(&mut self, input: &[u8]) -> io::Result<()> {
+        match self {
+            Self::OpenSSL(v) => v.update(input)?,
+            Self::Digest(v) => v.update(input),
+            Self::Blake2b(v) => {
+                v.update(input);
+            }
+            Self::Blake2s(v) => {
+                v.update(input);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finalize_into(self, output: &mut [u8]) -> io::Result<()> {
+        match self {
+            Self::OpenSSL(mut v) => output.copy_from_slice(&v.finish()?),
+            Self::Digest(mut v) => v
+                .finalize_into_reset(output)
+                .or(Err(io::Error::from(io::ErrorKind::InvalidInput)))?,
+            Self::Blake2b(v) => output.copy_from_slice(v.finalize().as_bytes()),
+            Self::Blake2s(v) => output.copy_from_slice(v.finalize().as_bytes()),
+        }
+
+        Ok(())
+    }
+}
+
+fn hash_blake3(path: &Path, output: &mut [u8]) -> io::Result<()> {
+    let mut hasher = blake3::Hasher::new();
+
+    #[cfg(feature = "rayon")]
+    hasher.update_mmap_rayon(path)?;
+    #[cfg(not(feature = "rayon"))]
+    hasher.update_mmap(path)?;
+
+    output.copy_from_slice(hasher.finalize().as_bytes());
+
+    Ok(())
+}
+
+fn hash_stream<R: Read>(hashtype: u64, mut reader: R, output: &mut [u8]) -> io::Result<()> {
+    let mut hasher = StreamHasher::new(hashtype)?;
+
+    let mut buf = vec![0u8; READ_BUFFER_SIZE];
+    loop {
+        let len = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(len) => len,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        hasher.update(&buf[..len])?;
+    }
+
+    hasher.finalize_into(output)
+}
+
+/// Computes the digest of the file at `path` without loading it into memory.
+///
+/// blake3 memory-maps the file and, with the `rayon` feature, hashes it on
+/// every core; the other algorithms stream the file through a large buffer.
+pub fn hash_file<P: AsRef<Path>>(path: P, hashtype: u64) -> io::Result<Vec<u8>> {
+    let len = try_hash_len(hashtype).ok_or_else(|| unsupported(hashtype))?;
+    let mut output = vec![0u8; len];
+
+    match hashtype {
+        0x1e => hash_blake3(path.as_ref(), &mut output)?,
+        _ => hash_stream(hashtype, File::open(path)?, &mut output)?,
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::*;
+    use crate::digest;
+
+    #[test]
+    fn test_hash_file() {
+        let input: Vec<u8> = (0..(3 * READ_BUFFER_SIZE + 17))
+            .map(|i| (i % 251) as u8)
+            .collect();
+
+        let path = env::temp_dir().join(format!("multihash-hash-file-{}", std::process::id()));
+        fs::write(&path, &input).unwrap();
+
+        for hashtype in [0x12, 0x13, 0x20, 0x1b, 0x1e, 0x1053, 0xb220, 0xb240, 0xb260] {
+            assert_eq!(
+                hash_file(&path, hashtype).unwrap(),
+                digest(hashtype, &input)
+            );
+        }
+
+        // identity and unknown codes are errors, not panics
+        for hashtype in [0x00, 0x13f, 0xb261] {
+            let err = hash_file(&path, hashtype).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        }
+
+        fs::remove_file(&path).unwrap();
+    }
+}
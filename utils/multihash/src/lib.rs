@@ -1,5 +1,9 @@
 extern crate openssl;
 
+mod file;
+
+pub use file::*;
+
 use digest::{DynDigest, OutputSizeUser};
 use openssl::hash::{Hasher, MessageDigest};
 
@@ -11,10 +15,19 @@ macro_rules! hash {
     }};
 }
 
+// blake2b-8 to blake2b-512 and blake2s-8 to blake2s-256 encode their digest
+// length in bytes in the code
+pub(crate) fn blake2_len(hashtype: u64) -> usize {
+    match hashtype {
+        0xb201..=0xb240 => (hashtype - 0xb200) as usize,
+        _ => (hashtype - 0xb240) as usize,
+    }
+}
+
 macro_rules! blake_hash {
     ($hash: expr, $hashtype: expr, $input: expr, $output: expr) => {{
         let mut params = $hash;
-        params.hash_length(($hashtype & 0x003f) as usize);
+        params.hash_length(blake2_len($hashtype));
 
         let mut state = params.to_state();
         state.update($input);
@@ -24,24 +37,28 @@ macro_rules! blake_hash {
     }};
 }
 
+pub(crate) fn message_digest(hashtype: u64) -> MessageDigest {
+    match hashtype {
+        0x11 => MessageDigest::sha1(),
+        0x12 => MessageDigest::sha256(),
+        0x13 => MessageDigest::sha512(),
+        0x14 => MessageDigest::sha3_512(),
+        0x15 => MessageDigest::sha3_384(),
+        0x16 => MessageDigest::sha3_256(),
+        0x17 => MessageDigest::sha3_224(),
+        0x18 => MessageDigest::shake_128(),
+        0x19 => MessageDigest::shake_256(),
+        0x20 => MessageDigest::sha384(),
+        _ => unreachable!(),
+    }
+}
+
 pub fn hash(hashtype: u64, input: &[u8], output: &mut [u8]) {
     match hashtype {
         0x11 | 0x12 | 0x13 | 0x14 | 0x15 | 0x16 | 0x17 | 0x18 | 0x19 | 0x20 => {
             // use openssl
 
-            let md = match hashtype {
-                0x11 => MessageDigest::sha1(),
-                0x12 => MessageDigest::sha256(),
-                0x13 => MessageDigest::sha256(),
-                0x14 => MessageDigest::sha3_512(),
-                0x15 => MessageDigest::sha3_384(),
-                0x16 => MessageDigest::sha3_256(),
-                0x17 => MessageDigest::sha3_224(),
-                0x18 => MessageDigest::shake_128(),
-                0x19 => MessageDigest::shake_256(),
-                0x20 => MessageDigest::sha3_384(),
-                _ => unreachable!(),
-            };
+            let md = message_digest(hashtype);
 
             debug_assert_eq!(output.len(), md.size());
 
@@ -74,18 +91,19 @@ pub fn hash(hashtype: u64, input: &[u8], output: &mut [u8]) {
     }
 }
 
-pub fn hash_len(hashtype: u64) -> usize {
-    match hashtype {
+/// Returns the digest length of `hashtype`, or `None` if it is not supported.
+pub fn try_hash_len(hashtype: u64) -> Option<usize> {
+    let len = match hashtype {
         0x11 => MessageDigest::sha1().size(),
         0x12 => MessageDigest::sha256().size(),
-        0x13 => MessageDigest::sha256().size(),
+        0x13 => MessageDigest::sha512().size(),
         0x14 => MessageDigest::sha3_512().size(),
         0x15 => MessageDigest::sha3_384().size(),
         0x16 => MessageDigest::sha3_256().size(),
         0x17 => MessageDigest::sha3_224().size(),
         0x18 => MessageDigest::shake_128().size(),
         0x19 => MessageDigest::shake_256().size(),
-        0x20 => MessageDigest::sha3_384().size(),
+        0x20 => MessageDigest::sha384().size(),
 
         0x1052 => ripemd::Ripemd128Core::output_size(),
         0x1053 => ripemd::Ripemd160Core::output_size(),
@@ -99,22 +117,46 @@ pub fn hash_len(hashtype: u64) -> usize {
 
         0x1e => blake3::OUT_LEN,
 
-        0xb201..=0xb260 => (hashtype & 0x003f) as usize,
+        0xb201..=0xb260 => blake2_len(hashtype),
+
+        _ => return None,
+    };
+
+    Some(len)
+}
 
+pub fn hash_len(hashtype: u64) -> usize {
+    match try_hash_len(hashtype) {
+        Some(len) => len,
         _ => unreachable!(),
     }
 }
 
 pub fn digest(hashtype: u64, input: &[u8]) -> Vec<u8> {
     let len = hash_len(hashtype);
-    let mut output = Vec::with_capacity(len);
-    unsafe { output.set_len(len) };
+    let mut output = vec![0u8; len];
 
     hash(hashtype, input, output.as_mut_slice());
 
     output
 }
 
+/// Splits a multihash into its hash type and digest, `None` unless the
+/// digest has exactly the length it declares.
+pub fn split_multihash(hash: &[u8]) -> Option<(u64, &[u8])> {
+    use varint::Varint;
+
+    let (hashtype, hashtype_len) = u64::decode_varint(hash)?;
+    let (len, len_len) = u64::decode_varint(&hash[hashtype_len..])?;
+
+    let digest = &hash[hashtype_len + len_len..];
+    if digest.len() as u64 != len {
+        return None;
+    }
+
+    Some((hashtype, digest))
+}
+
 pub fn multihash(hashtype: &str, input: &[u8]) -> Vec<u8> {
     use varint::Varint;
 
@@ -124,8 +166,7 @@ pub fn multihash(hashtype: &str, input: &[u8]) -> Vec<u8> {
     let ht_len = varint::required_encode_space(hashtype);
     let dig_len = varint::required_encode_space(len as u64);
 
-    let mut output = Vec::with_capacity(ht_len + dig_len + len);
-    unsafe { output.set_len(ht_len + dig_len + len) };
+    let mut output = vec![0u8; ht_len + dig_len + len];
 
     hashtype.encode_varint(&mut output.as_mut_slice()[..ht_len]);
     (len as u64).encode_varint(&mut output.as_mut_slice()[ht_len..(ht_len + dig_len)]);
@@ -138,3 +179,44 @@ pub fn multihash(hashtype: &str, input: &[u8]) -> Vec<u8> {
 
     output
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_digest_vectors() {
+        for (hashtype, expected) in [
+            (
+                0x13,
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+            ),
+            (
+                0x20,
+                "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7",
+            ),
+            (
+                0xb240,
+                "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923",
+            ),
+            (
+                0xb260,
+                "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982",
+            ),
+        ] {
+            assert_eq!(try_hash_len(hashtype), Some(expected.len() / 2));
+            assert_eq!(hex(&digest(hashtype, b"abc")), expected, "0x{:x}", hashtype);
+        }
+
+        assert_eq!(try_hash_len(0xb201), Some(1));
+        assert_eq!(try_hash_len(0xb241), Some(1));
+        assert_eq!(
+            multihash("blake2b-512", b"abc")[..4],
+            [0xc0, 0xe4, 0x02, 0x40]
+        );
+    }
+}